@@ -1,10 +1,16 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use clap::Parser;
 use clap::Subcommand;
+use clap::ValueEnum;
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::{CommandFactory, error::ErrorKind};
+use ix_app::graph::LinkTitle;
 use serde_json::json;
-use serde_yaml::Value as YamlValue;
+use serde_yaml::{Mapping, Value};
 
 #[derive(Parser, Debug)]
 #[command(name = "ixchel", version)]
@@ -16,36 +22,106 @@ struct Cli {
     #[arg(long, global = true)]
     repo: Option<PathBuf>,
 
+    /// Use this config file instead of discovered config (overrides `IXCHEL_CONFIG`).
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
     #[arg(long, global = true)]
     json: bool,
 }
 
+const DAEMON_TOOL: &str = "ixchel";
+const DAEMON_SYNC_TIMEOUT_MS: u64 = 10 * 60 * 1000;
+/// Quiet period after the last file event before `watch --foreground` re-syncs.
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Global flags that decide which repo to open and how to configure it.
+#[derive(Debug)]
+struct RepoArgs {
+    start: PathBuf,
+    config: Option<PathBuf>,
+}
+
+impl RepoArgs {
+    fn open(&self) -> Result<ix_core::repo::IxchelRepo> {
+        ix_core::repo::IxchelRepo::open_from_with_config(&self.start, self.config.as_deref())
+    }
+}
+
+/// Parses an entity kind via `FromStr` (so plurals still work) while advertising the
+/// canonical names to `--help` and shell completions.
+#[derive(Clone, Copy, Debug)]
+struct EntityKindParser;
+
+impl TypedValueParser for EntityKindParser {
+    type Value = ix_core::entity::EntityKind;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        _arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> std::result::Result<Self::Value, clap::Error> {
+        let raw = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        raw.parse().map_err(|err| {
+            clap::Error::raw(ErrorKind::InvalidValue, format!("{err}\n")).with_cmd(cmd)
+        })
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            ix_core::entity::EntityKind::ALL
+                .iter()
+                .map(|kind| PossibleValue::new(kind.as_str())),
+        ))
+    }
+}
+
 #[derive(Subcommand, Debug)]
 enum Command {
     Init {
         #[arg(long)]
         force: bool,
+        /// Also write a sample config and an example decision.
+        #[arg(long)]
+        with_examples: bool,
     },
 
     Create {
+        #[arg(value_parser = EntityKindParser)]
         kind: ix_core::entity::EntityKind,
         title: String,
         #[arg(long)]
         status: Option<String>,
+        /// Print only the new entity's path or id (for piping into other commands).
+        #[arg(long, value_enum)]
+        print: Option<CreatePrint>,
     },
 
     Show {
         id: String,
+        /// Annotate relationship ids with their target's title.
+        #[arg(long)]
+        resolve_links: bool,
     },
 
     List {
+        #[arg(value_parser = EntityKindParser)]
         kind: Option<ix_core::entity::EntityKind>,
         #[arg(long, default_value = "recent")]
         sort: ix_core::repo::ListSort,
+        /// Include archived entities.
+        #[arg(long)]
+        include_archived: bool,
+        /// Only list entities carrying this tag (repeatable; all must match).
+        #[arg(long = "tag")]
+        tags: Vec<String>,
     },
 
     Tags {
-        #[arg(long)]
+        #[arg(long, value_parser = EntityKindParser)]
         kind: Option<ix_core::entity::EntityKind>,
         #[arg(long)]
         untagged: bool,
@@ -57,9 +133,19 @@ enum Command {
     },
 
     Link {
-        from: String,
-        rel: String,
-        to: String,
+        #[arg(required_unless_present = "from_file", requires_all = ["rel", "to"])]
+        from: Option<String>,
+        rel: Option<String>,
+        to: Option<String>,
+        /// Apply `from<TAB>rel<TAB>to` lines from a file (`-` for stdin).
+        #[arg(long, conflicts_with = "from")]
+        from_file: Option<PathBuf>,
+        /// With --from-file, apply nothing if any line is invalid or any source can't be read.
+        #[arg(long, requires = "from_file")]
+        strict: bool,
+        /// Allow linking to an entity that does not exist yet.
+        #[arg(long)]
+        allow_dangling: bool,
     },
 
     Unlink {
@@ -70,16 +156,62 @@ enum Command {
 
     Check,
 
-    Sync,
+    /// Check entities against their kind's `[schema.<kind>]` config only.
+    ValidateSchema,
+
+    Sync {
+        /// Enqueue the sync on the `ixcheld` daemon and wait for it to finish.
+        #[arg(long)]
+        daemon: bool,
+    },
+
+    /// Show the index manifest written by the last sync.
+    Status,
+
+    /// Diagnose the repository, config, index, and embedder.
+    Doctor,
+
+    /// Print a shell completion script to stdout.
+    Completions {
+        shell: clap_complete::Shell,
+    },
+
+    /// Drop the index and rebuild it from the entity files.
+    Gc {
+        /// Recompute every embedding instead of reusing unchanged ones.
+        #[arg(long)]
+        reembed: bool,
+    },
 
     Search {
-        query: String,
+        /// Query text, or `-` to read the query from stdin.
+        #[arg(required_unless_present = "query_file", conflicts_with = "query_file")]
+        query: Option<String>,
+        /// Read the query from a file (`-` for stdin).
+        #[arg(long)]
+        query_file: Option<PathBuf>,
         #[arg(short, long, default_value_t = 10)]
         limit: usize,
+        /// Include archived entities.
+        #[arg(long)]
+        include_archived: bool,
+        /// Drop hits whose normalized score (0-1, relative to the top candidates) is below this.
+        #[arg(long, value_parser = parse_min_score)]
+        min_score: Option<f32>,
     },
 
     Graph {
-        id: String,
+        #[arg(required_unless_present = "all")]
+        id: Option<String>,
+        /// Emit the graph for every entity in the repository.
+        #[arg(long, conflicts_with = "id")]
+        all: bool,
+        /// Output format (defaults to `text`, or `json` with --json).
+        #[arg(long, value_enum)]
+        format: Option<GraphFormat>,
+        /// JSON-LD `@context` file (defaults to `.ixchel/context.jsonld` when present).
+        #[arg(long)]
+        context: Option<PathBuf>,
     },
 
     Context {
@@ -88,6 +220,19 @@ enum Command {
 
     Delete {
         id: String,
+        /// Move the entity to `.ixchel/archive/` instead of deleting it.
+        #[arg(long)]
+        archive: bool,
+    },
+
+    /// Move an entity to another kind, rewriting its id and inbound links.
+    Mv {
+        id: String,
+        #[arg(long, value_parser = EntityKindParser)]
+        kind: ix_core::entity::EntityKind,
+        /// Show what would change without writing anything.
+        #[arg(long)]
+        dry_run: bool,
     },
 
     Edit {
@@ -100,12 +245,26 @@ enum Command {
         #[arg(long)]
         unwatch: bool,
 
-        /// Run watcher in foreground (local, without daemon).
+        /// Run watcher in foreground, syncing the index locally instead of via the daemon.
         #[arg(long)]
         foreground: bool,
     },
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum GraphFormat {
+    Text,
+    Json,
+    #[value(name = "jsonld", alias = "json-ld")]
+    JsonLd,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum CreatePrint {
+    Path,
+    Id,
+}
+
 #[derive(Subcommand, Debug)]
 enum TagCommand {
     Add {
@@ -122,40 +281,117 @@ enum TagCommand {
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
-    let start = cli.repo.clone().unwrap_or(std::env::current_dir()?);
-    run(cli.command, &start, cli.json)
+    let repo_args = RepoArgs {
+        start: cli.repo.unwrap_or(std::env::current_dir()?),
+        config: cli.config,
+    };
+    run(cli.command, &repo_args, cli.json)
 }
 
-fn run(command: Command, start: &Path, json_output: bool) -> Result<()> {
+fn run(command: Command, repo_args: &RepoArgs, json_output: bool) -> Result<()> {
     match command {
-        Command::Init { force } => cmd_init(start, force, json_output),
+        Command::Init {
+            force,
+            with_examples,
+        } => cmd_init(repo_args, force, with_examples, json_output),
         Command::Create {
             kind,
             title,
             status,
-        } => cmd_create(start, kind, &title, status.as_deref(), json_output),
-        Command::Show { id } => cmd_show(start, &id, json_output),
-        Command::List { kind, sort } => cmd_list(start, kind, sort, json_output),
-        Command::Tags { kind, untagged } => cmd_tags(start, kind, untagged, json_output),
-        Command::Tag { command } => cmd_tag(start, command, json_output),
-        Command::Link { from, rel, to } => cmd_link(start, &from, &rel, &to, json_output),
-        Command::Unlink { from, rel, to } => cmd_unlink(start, &from, &rel, &to, json_output),
-        Command::Check => cmd_check(start, json_output),
-        Command::Sync => cmd_sync(start, json_output),
-        Command::Search { query, limit } => cmd_search(start, &query, limit, json_output),
-        Command::Graph { id } => cmd_graph(start, &id, json_output),
-        Command::Context { id } => cmd_context(start, &id, json_output),
-        Command::Delete { id } => cmd_delete(start, &id, json_output),
-        Command::Edit { id } => cmd_edit(start, &id, json_output),
+            print,
+        } => cmd_create(
+            repo_args,
+            kind,
+            &title,
+            status.as_deref(),
+            print,
+            json_output,
+        ),
+        Command::Show { id, resolve_links } => cmd_show(repo_args, &id, resolve_links, json_output),
+        Command::List {
+            kind,
+            sort,
+            include_archived,
+            tags,
+        } => cmd_list(repo_args, kind, sort, include_archived, &tags, json_output),
+        Command::Tags { kind, untagged } => cmd_tags(repo_args, kind, untagged, json_output),
+        Command::Tag { command } => cmd_tag(repo_args, command, json_output),
+        Command::Link {
+            from,
+            rel,
+            to,
+            from_file,
+            strict,
+            allow_dangling,
+        } => match (from, rel, to, from_file) {
+            (_, _, _, Some(path)) => {
+                cmd_link_batch(repo_args, &path, strict, allow_dangling, json_output)
+            }
+            (Some(from), Some(rel), Some(to), None) => {
+                cmd_link(repo_args, &from, &rel, &to, allow_dangling, json_output)
+            }
+            _ => anyhow::bail!("Provide <FROM> <REL> <TO> or --from-file"),
+        },
+        Command::Unlink { from, rel, to } => cmd_unlink(repo_args, &from, &rel, &to, json_output),
+        Command::Check => cmd_check(repo_args, json_output),
+        Command::ValidateSchema => cmd_validate_schema(repo_args, json_output),
+        Command::Sync { daemon } => cmd_sync(repo_args, daemon, json_output),
+        Command::Status => cmd_status(repo_args, json_output),
+        Command::Gc { reembed } => cmd_gc(repo_args, reembed, json_output),
+        Command::Doctor => cmd_doctor(repo_args, json_output),
+        Command::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "ixchel", &mut std::io::stdout());
+            Ok(())
+        }
+        Command::Search {
+            query,
+            query_file,
+            limit,
+            include_archived,
+            min_score,
+        } => {
+            let query = read_search_query(query, query_file.as_deref())?;
+            cmd_search(
+                repo_args,
+                &query,
+                limit,
+                include_archived,
+                min_score,
+                json_output,
+            )
+        }
+        Command::Graph {
+            id,
+            all,
+            format,
+            context,
+        } => {
+            let format = format.unwrap_or(if json_output {
+                GraphFormat::Json
+            } else {
+                GraphFormat::Text
+            });
+            cmd_graph(repo_args, id.as_deref(), all, format, context.as_deref())
+        }
+        Command::Context { id } => cmd_context(repo_args, &id, json_output),
+        Command::Delete { id, archive } => cmd_delete(repo_args, &id, archive, json_output),
+        Command::Mv { id, kind, dry_run } => cmd_mv(repo_args, &id, kind, dry_run, json_output),
+        Command::Edit { id } => cmd_edit(repo_args, &id, json_output),
         Command::Watch {
             unwatch,
             foreground,
-        } => cmd_watch(start, unwatch, foreground, json_output),
+        } => cmd_watch(repo_args, unwatch, foreground, json_output),
     }
 }
 
-fn cmd_init(start: &Path, force: bool, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::init_from(start, force)?;
+fn cmd_init(
+    repo_args: &RepoArgs,
+    force: bool,
+    with_examples: bool,
+    json_output: bool,
+) -> Result<()> {
+    let repo =
+        ix_core::repo::IxchelRepo::init_from_with_options(&repo_args.start, force, with_examples)?;
     if json_output {
         print_json(&json!({ "ixchel_dir": repo.paths.ixchel_dir() }))?;
     } else {
@@ -165,13 +401,14 @@ fn cmd_init(start: &Path, force: bool, json_output: bool) -> Result<()> {
 }
 
 fn cmd_create(
-    start: &Path,
+    repo_args: &RepoArgs,
     kind: ix_core::entity::EntityKind,
     title: &str,
     status: Option<&str>,
+    print: Option<CreatePrint>,
     json_output: bool,
 ) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+    let repo = repo_args.open()?;
     let created = repo.create_entity(kind, title, status)?;
     if json_output {
         print_json(&json!({
@@ -181,13 +418,22 @@ fn cmd_create(
             "path": created.path,
         }))?;
     } else {
-        println!("Created {} ({})", created.id, created.path.display());
+        match print {
+            Some(CreatePrint::Path) => println!("{}", created.path.display()),
+            Some(CreatePrint::Id) => println!("{}", created.id),
+            None => println!("Created {} ({})", created.id, created.path.display()),
+        }
     }
     Ok(())
 }
 
-fn cmd_show(start: &Path, id: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_show(repo_args: &RepoArgs, id: &str, resolve_links: bool, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let id = &repo.resolve_id(id)?;
+    if resolve_links {
+        return show_resolved(&repo, id, json_output);
+    }
+
     let raw = repo.read_raw(id)?;
     if json_output {
         print_json(&json!({ "id": id, "raw": raw }))?;
@@ -197,14 +443,144 @@ fn cmd_show(start: &Path, id: &str, json_output: bool) -> Result<()> {
     Ok(())
 }
 
+fn show_resolved(repo: &ix_core::repo::IxchelRepo, id: &str, json_output: bool) -> Result<()> {
+    let doc = repo.read_document(id)?;
+    let rels = extract_relationships(repo, &doc.frontmatter);
+    let ids = rels
+        .iter()
+        .flat_map(|(_, targets)| targets.iter().cloned())
+        .collect::<Vec<_>>();
+    let titles = ix_app::graph::link_titles(repo, &ids);
+
+    if json_output {
+        let mut frontmatter = match serde_json::to_value(&doc.frontmatter)? {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        for (rel, targets) in rels {
+            let resolved = targets
+                .into_iter()
+                .map(|target| {
+                    let link = titles.get(&target);
+                    json!({
+                        "id": target,
+                        "title": link.and_then(LinkTitle::title),
+                        "exists": link.is_some_and(LinkTitle::exists),
+                    })
+                })
+                .collect::<Vec<_>>();
+            frontmatter.insert(rel, serde_json::Value::Array(resolved));
+        }
+        return print_json(&json!({ "id": id, "frontmatter": frontmatter, "body": doc.body }));
+    }
+
+    print!("{}", annotate_links(repo, &doc, &titles)?);
+    Ok(())
+}
+
+/// Render `doc` with `# "Title"`, `# (untitled)` or `# (missing)` after each relationship id.
+fn annotate_links(
+    repo: &ix_core::repo::IxchelRepo,
+    doc: &ix_core::markdown::MarkdownDocument,
+    titles: &BTreeMap<String, LinkTitle>,
+) -> Result<String> {
+    let note = |id: &str| match titles.get(id) {
+        Some(LinkTitle::Titled(title)) => format!(" # \"{title}\""),
+        Some(LinkTitle::Untitled) => " # (untitled)".to_string(),
+        Some(LinkTitle::Missing) => " # (missing)".to_string(),
+        None => String::new(),
+    };
+
+    let mut out = String::from("---\n");
+    for (key, value) in &doc.frontmatter {
+        let is_rel = key.as_str().is_some_and(|k| repo.is_relationship_key(k));
+        match value {
+            Value::Sequence(items) if is_rel && !items.is_empty() => {
+                out.push_str(&to_yaml(key)?);
+                out.push_str(":\n");
+                for item in items {
+                    out.push_str(&annotate_target(item, "- ", "  ", &note)?);
+                }
+            }
+            Value::String(_) if is_rel => {
+                let first = format!("{}: ", to_yaml(key)?);
+                out.push_str(&annotate_target(value, &first, "", &note)?);
+            }
+            Value::Mapping(_) if is_rel => {
+                out.push_str(&to_yaml(key)?);
+                out.push_str(":\n");
+                out.push_str(&annotate_target(value, "  ", "  ", &note)?);
+            }
+            _ => {
+                let mut entry = Mapping::new();
+                entry.insert(key.clone(), value.clone());
+                out.push_str(&to_yaml(&Value::Mapping(entry))?);
+                out.push('\n');
+            }
+        }
+    }
+    out.push_str("---\n\n");
+    out.push_str(&doc.body);
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// One relationship entry, bare (`dec-x`) or qualified (`id: dec-x` plus properties).
+fn annotate_target(
+    target: &Value,
+    first: &str,
+    rest: &str,
+    note: &impl Fn(&str) -> String,
+) -> Result<String> {
+    match target {
+        Value::String(id) => Ok(format!("{first}{}{}\n", to_yaml(target)?, note(id))),
+        Value::Mapping(map) => {
+            let Some(Value::String(id)) = map.get("id") else {
+                return Ok(indent_yaml(&to_yaml(target)?, first, rest));
+            };
+            let mut out = format!("{first}id: {}{}\n", to_yaml(&map["id"])?, note(id));
+            let mut properties = map.clone();
+            properties.remove("id");
+            if !properties.is_empty() {
+                out.push_str(&indent_yaml(
+                    &to_yaml(&Value::Mapping(properties))?,
+                    rest,
+                    rest,
+                ));
+            }
+            Ok(out)
+        }
+        _ => Ok(indent_yaml(&to_yaml(target)?, first, rest)),
+    }
+}
+
+fn to_yaml(value: &Value) -> Result<String> {
+    let yaml = serde_yaml::to_string(value).context("Failed to render frontmatter")?;
+    Ok(yaml.trim_end_matches('\n').to_string())
+}
+
+fn indent_yaml(yaml: &str, first: &str, rest: &str) -> String {
+    let mut out = String::with_capacity(yaml.len());
+    for (i, line) in yaml.lines().enumerate() {
+        out.push_str(if i == 0 { first } else { rest });
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
 fn cmd_list(
-    start: &Path,
+    repo_args: &RepoArgs,
     kind: Option<ix_core::entity::EntityKind>,
     sort: ix_core::repo::ListSort,
+    include_archived: bool,
+    tags: &[String],
     json_output: bool,
 ) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
-    let items = repo.list(kind, sort)?;
+    let repo = repo_args.open()?;
+    let items = repo.list_tagged(kind, sort, include_archived, tags)?;
     if json_output {
         let items = items
             .into_iter()
@@ -227,12 +603,12 @@ fn cmd_list(
 }
 
 fn cmd_tags(
-    start: &Path,
+    repo_args: &RepoArgs,
     kind: Option<ix_core::entity::EntityKind>,
     untagged: bool,
     json_output: bool,
 ) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+    let repo = repo_args.open()?;
     if untagged {
         let items = repo.list_untagged(kind)?;
         let total = items.len();
@@ -273,7 +649,7 @@ fn cmd_tags(
             .into_iter()
             .map(|(tag, ids)| (tag, ids.len()))
             .collect::<Vec<_>>();
-        items.sort_by(|a, b| a.0.cmp(&b.0));
+        items.sort_by_cached_key(|(tag, _)| (tag.to_lowercase(), tag.clone()));
 
         if json_output {
             let tags = items
@@ -291,15 +667,15 @@ fn cmd_tags(
     Ok(())
 }
 
-fn cmd_tag(start: &Path, command: TagCommand, json_output: bool) -> Result<()> {
+fn cmd_tag(repo_args: &RepoArgs, command: TagCommand, json_output: bool) -> Result<()> {
     match command {
-        TagCommand::Add { id, tags } => cmd_tag_add(start, &id, &tags, json_output),
-        TagCommand::Remove { id, tags } => cmd_tag_remove(start, &id, &tags, json_output),
+        TagCommand::Add { id, tags } => cmd_tag_add(repo_args, &id, &tags, json_output),
+        TagCommand::Remove { id, tags } => cmd_tag_remove(repo_args, &id, &tags, json_output),
     }
 }
 
-fn cmd_tag_add(start: &Path, id: &str, tags: &[String], json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_tag_add(repo_args: &RepoArgs, id: &str, tags: &[String], json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
     let changed = repo.add_tags(id, tags)?;
 
     if json_output {
@@ -317,8 +693,13 @@ fn cmd_tag_add(start: &Path, id: &str, tags: &[String], json_output: bool) -> Re
     Ok(())
 }
 
-fn cmd_tag_remove(start: &Path, id: &str, tags: &[String], json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_tag_remove(
+    repo_args: &RepoArgs,
+    id: &str,
+    tags: &[String],
+    json_output: bool,
+) -> Result<()> {
+    let repo = repo_args.open()?;
     let changed = repo.remove_tags(id, tags)?;
 
     if json_output {
@@ -336,9 +717,17 @@ fn cmd_tag_remove(start: &Path, id: &str, tags: &[String], json_output: bool) ->
     Ok(())
 }
 
-fn cmd_link(start: &Path, from: &str, rel: &str, to: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
-    repo.link(from, rel, to)?;
+fn cmd_link(
+    repo_args: &RepoArgs,
+    from: &str,
+    rel: &str,
+    to: &str,
+    allow_dangling: bool,
+    json_output: bool,
+) -> Result<()> {
+    let repo = repo_args.open()?;
+    repo.link_with_options(from, rel, to, allow_dangling)
+        .map_err(with_dangling_hint)?;
     if json_output {
         print_json(&json!({ "from": from, "rel": rel, "to": to, "changed": true }))?;
     } else {
@@ -347,8 +736,108 @@ fn cmd_link(start: &Path, from: &str, rel: &str, to: &str, json_output: bool) ->
     Ok(())
 }
 
-fn cmd_unlink(start: &Path, from: &str, rel: &str, to: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_link_batch(
+    repo_args: &RepoArgs,
+    path: &Path,
+    strict: bool,
+    allow_dangling: bool,
+    json_output: bool,
+) -> Result<()> {
+    let raw = if path == Path::new("-") {
+        std::io::read_to_string(std::io::stdin()).context("Failed to read links from stdin")?
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+    };
+
+    let mut links = Vec::new();
+    let mut line_numbers = Vec::new();
+    let mut errors = Vec::new();
+    for (idx, line) in raw.lines().enumerate() {
+        let line_number = idx + 1;
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+        match line.split('\t').map(str::trim).collect::<Vec<_>>()[..] {
+            [from, rel, to] if !from.is_empty() && !rel.is_empty() && !to.is_empty() => {
+                links.push(ix_core::repo::LinkRequest {
+                    from: from.to_string(),
+                    rel: rel.to_string(),
+                    to: to.to_string(),
+                });
+                line_numbers.push(line_number);
+            }
+            _ => errors.push((line_number, "expected from<TAB>rel<TAB>to".to_string())),
+        }
+    }
+    if strict && let Some((line, message)) = errors.first() {
+        anyhow::bail!("{}:{line}: {message}", path.display());
+    }
+
+    let repo = repo_args.open()?;
+    let report = repo
+        .link_many(&links, allow_dangling, strict)
+        .map_err(with_dangling_hint)
+        .with_context(|| format!("Failed to apply links from {}", path.display()))?;
+    let missing_target = report.errors.iter().any(|e| e.missing_target);
+    errors.extend(
+        report
+            .errors
+            .into_iter()
+            .map(|e| (line_numbers[e.index], e.message)),
+    );
+    errors.sort_by_key(|(line, _)| *line);
+
+    if json_output {
+        let errors = errors
+            .iter()
+            .map(|(line, message)| json!({ "line": line, "message": message }))
+            .collect::<Vec<_>>();
+        print_json(&json!({
+            "added": report.added,
+            "skipped": report.skipped,
+            "errors": errors,
+        }))?;
+    } else {
+        for (line, message) in &errors {
+            eprintln!("{}:{line}: {message}", path.display());
+        }
+        if missing_target {
+            eprintln!("Hint: {DANGLING_HINT}");
+        }
+        println!(
+            "Linked {} ({} already present, {} failed)",
+            report.added,
+            report.skipped,
+            errors.len()
+        );
+    }
+
+    if !errors.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+const DANGLING_HINT: &str = "Pass --allow-dangling to link to an entity you will create later.";
+
+/// Append the `--allow-dangling` hint when a link failed only because its target is missing.
+fn with_dangling_hint(err: anyhow::Error) -> anyhow::Error {
+    if err.downcast_ref::<ix_core::repo::LinkError>().is_some() {
+        anyhow::anyhow!("{err:#}. {DANGLING_HINT}")
+    } else {
+        err
+    }
+}
+
+fn cmd_unlink(
+    repo_args: &RepoArgs,
+    from: &str,
+    rel: &str,
+    to: &str,
+    json_output: bool,
+) -> Result<()> {
+    let repo = repo_args.open()?;
     let removed = repo.unlink(from, rel, to)?;
     if json_output {
         print_json(&json!({ "from": from, "rel": rel, "to": to, "changed": removed }))?;
@@ -360,8 +849,8 @@ fn cmd_unlink(start: &Path, from: &str, rel: &str, to: &str, json_output: bool)
     Ok(())
 }
 
-fn cmd_check(start: &Path, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_check(repo_args: &RepoArgs, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
     let report = repo.check_with_suggestions()?;
     let errors = report.errors;
     if json_output {
@@ -396,9 +885,100 @@ fn cmd_check(start: &Path, json_output: bool) -> Result<()> {
     Ok(())
 }
 
-fn cmd_sync(start: &Path, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
-    let stats = ix_app::sync(&repo)?;
+fn cmd_validate_schema(repo_args: &RepoArgs, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let checks = repo.validate_schema()?;
+    let ok = checks.iter().all(|c| c.violations.is_empty());
+
+    if json_output {
+        let entities = checks
+            .iter()
+            .map(|c| {
+                let status = if !c.has_schema {
+                    "skipped"
+                } else if c.violations.is_empty() {
+                    "ok"
+                } else {
+                    "invalid"
+                };
+                let violations = c
+                    .violations
+                    .iter()
+                    .map(|v| {
+                        json!({
+                            "field": v.field,
+                            "message": v.message,
+                            "suggestion": v.suggestion,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                json!({
+                    "id": c.id,
+                    "kind": c.kind.as_str(),
+                    "path": c.path,
+                    "status": status,
+                    "violations": violations,
+                })
+            })
+            .collect::<Vec<_>>();
+        print_json(&json!({ "ok": ok, "entities": entities }))?;
+    } else {
+        for check in &checks {
+            if !check.has_schema {
+                println!("{}: no schema, skipped", check.id);
+            }
+            for violation in &check.violations {
+                eprintln!(
+                    "{}: {}: {}",
+                    check.path.display(),
+                    violation.field,
+                    violation.message
+                );
+                if let Some(suggestion) = &violation.suggestion {
+                    eprintln!("  suggestion: {suggestion}");
+                }
+            }
+        }
+        if ok {
+            println!("OK");
+        }
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn cmd_sync(repo_args: &RepoArgs, daemon: bool, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let stats = if daemon {
+        if repo_args.config.is_some() {
+            anyhow::bail!("--config cannot be forwarded to the daemon; sync without --daemon");
+        }
+        if std::env::var_os(ix_core::repo::CONFIG_ENV_VAR).is_some_and(|value| !value.is_empty()) {
+            anyhow::bail!(
+                "{} cannot be forwarded to the daemon; unset it or sync without --daemon",
+                ix_core::repo::CONFIG_ENV_VAR
+            );
+        }
+        sync_via_daemon(&repo)?
+    } else {
+        let show_progress = !json_output && std::io::stderr().is_terminal();
+        let stats = ix_app::sync_with_progress(&repo, &mut |stats| {
+            if show_progress {
+                eprint!(
+                    "\rSyncing: scanned={} added={} modified={} unchanged={}",
+                    stats.scanned, stats.added, stats.modified, stats.unchanged
+                );
+            }
+        })?;
+        if show_progress {
+            eprintln!();
+        }
+        stats
+    };
+
     if json_output {
         print_json(&json!({
             "scanned": stats.scanned,
@@ -416,16 +996,239 @@ fn cmd_sync(start: &Path, json_output: bool) -> Result<()> {
     Ok(())
 }
 
-fn cmd_search(start: &Path, query: &str, limit: usize, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
-    let hits = ix_app::search(&repo, query, limit)?;
+fn sync_via_daemon(repo: &ix_core::repo::IxchelRepo) -> Result<ix_core::index::SyncStats> {
+    let repo_root = repo.paths.repo_root().to_string_lossy().to_string();
+    let rt = tokio::runtime::Runtime::new()?;
+    let client = ix_daemon::Client::new();
+
+    let response = rt
+        .block_on(async {
+            let sync_id = client
+                .enqueue_sync(&repo_root, DAEMON_TOOL, ".ixchel", false)
+                .await?;
+            client
+                .wait_sync_response(&repo_root, DAEMON_TOOL, &sync_id, DAEMON_SYNC_TIMEOUT_MS)
+                .await
+        })
+        .map_err(|e| anyhow::anyhow!("Daemon sync failed: {e}"))?;
+
+    match response.state {
+        ix_daemon::SyncState::Done => {}
+        ix_daemon::SyncState::Error => anyhow::bail!(
+            "Daemon sync failed: {}",
+            response.error.as_deref().unwrap_or("unknown error")
+        ),
+        state => anyhow::bail!("Daemon sync did not finish (state: {state:?})"),
+    }
+
+    let stats = response.stats.unwrap_or_default();
+    let count = |n: u64| u32::try_from(n).unwrap_or(u32::MAX);
+    Ok(ix_core::index::SyncStats {
+        scanned: count(stats.files_scanned),
+        added: count(stats.files_added),
+        modified: count(stats.files_modified),
+        deleted: count(stats.files_deleted),
+        unchanged: count(stats.files_unchanged),
+    })
+}
+
+fn cmd_status(repo_args: &RepoArgs, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let manifest = ix_app::index_status(&repo)?;
+    let warnings = ix_app::index_warnings(&repo)?;
+
+    if json_output {
+        print_json(&json!({
+            "synced": manifest.is_some(),
+            "manifest": manifest,
+            "warnings": warnings,
+        }))?;
+        return Ok(());
+    }
+
+    let Some(manifest) = manifest else {
+        println!("Index has not been synced yet. Run `ixchel sync`.");
+        return Ok(());
+    };
+
+    println!("Backend: {}", manifest.backend);
+    println!("Schema version: {}", manifest.schema_version);
+    match (&manifest.embedding_model, manifest.embedding_dimension) {
+        (Some(model), Some(dimension)) => println!("Embedding: {model} ({dimension} dims)"),
+        (Some(model), None) => println!("Embedding: {model}"),
+        _ => println!("Embedding: none"),
+    }
+    let counts = manifest
+        .kind_counts
+        .iter()
+        .map(|(kind, count)| format!("{kind}={count}"))
+        .collect::<Vec<_>>();
+    println!("Entities: {} ({})", manifest.entity_count, counts.join(" "));
+    println!("Last sync: {}", manifest.last_sync.to_rfc3339());
+    for warning in warnings {
+        eprintln!("warning: {warning}");
+    }
+    Ok(())
+}
+
+fn cmd_doctor(repo_args: &RepoArgs, json_output: bool) -> Result<()> {
+    use ix_core::repo::{HealthCheck, HealthStatus};
+
+    let mut checks = Vec::new();
+    let git_root = ix_core::paths::find_git_root(&repo_args.start);
+    checks.push(git_root.as_ref().map_or_else(
+        || {
+            HealthCheck::fail(
+                "git",
+                format!("no .git found above {}", repo_args.start.display()),
+                "Run ixchel inside a git repository, or `git init` one first.",
+            )
+        },
+        |root| HealthCheck::pass("git", root.display().to_string()),
+    ));
+    // Every other check needs an opened repo, which needs a git root.
+    if git_root.is_some() {
+        match repo_args.open() {
+            Ok(repo) => {
+                checks.push(HealthCheck::pass(
+                    "config",
+                    repo.paths.ixchel_dir().display().to_string(),
+                ));
+                checks.extend(repo.health_check());
+                checks.extend(ix_app::index_health(&repo));
+            }
+            Err(err) => checks.push(HealthCheck::fail(
+                "config",
+                format!("{err:#}"),
+                "Run `ixchel init`, or fix the config file.",
+            )),
+        }
+    }
+    let ok = checks.iter().all(|c| c.status != HealthStatus::Fail);
+
+    if json_output {
+        let checks = checks
+            .iter()
+            .map(|c| {
+                json!({
+                    "name": c.name,
+                    "status": c.status.as_str(),
+                    "detail": c.detail,
+                    "hint": c.hint,
+                })
+            })
+            .collect::<Vec<_>>();
+        print_json(&json!({ "ok": ok, "checks": checks }))?;
+    } else {
+        for check in &checks {
+            println!(
+                "[{}] {}: {}",
+                check.status.as_str(),
+                check.name,
+                check.detail
+            );
+            if let Some(hint) = &check.hint {
+                println!("  hint: {hint}");
+            }
+        }
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn cmd_gc(repo_args: &RepoArgs, reembed: bool, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let report = ix_app::gc(&repo, reembed)?;
+
+    if json_output {
+        print_json(&json!({
+            "entities_before": report.entities_before,
+            "entities_after": report.entities_after,
+            "bytes_before": report.bytes_before,
+            "bytes_after": report.bytes_after,
+            "reembed": reembed,
+        }))?;
+        return Ok(());
+    }
+
+    let before = report
+        .entities_before
+        .map_or_else(|| "unsynced".to_string(), |count| count.to_string());
+    println!(
+        "Rebuilt index: entities {before} -> {}, size {} -> {} bytes",
+        report.entities_after, report.bytes_before, report.bytes_after
+    );
+    Ok(())
+}
+
+fn read_search_query(query: Option<String>, query_file: Option<&Path>) -> Result<String> {
+    let source = match (query, query_file) {
+        (Some(query), None) if query != "-" => return Ok(query),
+        (_, Some(path)) => path,
+        (_, None) => Path::new("-"),
+    };
+
+    let raw = if source == Path::new("-") {
+        std::io::read_to_string(std::io::stdin()).context("Failed to read query from stdin")?
+    } else {
+        std::fs::read_to_string(source)
+            .with_context(|| format!("Failed to read {}", source.display()))?
+    };
+
+    let query = raw.trim_end_matches(['\r', '\n']);
+    if query.trim().is_empty() {
+        anyhow::bail!("Search query is empty");
+    }
+    Ok(query.to_string())
+}
+
+fn parse_min_score(raw: &str) -> std::result::Result<f32, String> {
+    let value = raw
+        .parse::<f32>()
+        .map_err(|err| format!("invalid score '{raw}': {err}"))?;
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!("score must be between 0 and 1, got {raw}"))
+    }
+}
+
+/// Candidates fetched before normalizing, so `normalized_score` does not depend on `--limit`.
+const SEARCH_SCORE_POOL: usize = 50;
+
+fn cmd_search(
+    repo_args: &RepoArgs,
+    query: &str,
+    limit: usize,
+    include_archived: bool,
+    min_score: Option<f32>,
+    json_output: bool,
+) -> Result<()> {
+    let repo = repo_args.open()?;
+    let pool = limit.max(SEARCH_SCORE_POOL);
+    let hits = ix_app::search_with_options(&repo, query, pool, include_archived)?;
+    for warning in ix_app::index_warnings(&repo)? {
+        eprintln!("warning: {warning}");
+    }
+
+    let normalized = ix_core::index::normalize_scores(&hits);
+    let hits = hits
+        .into_iter()
+        .zip(normalized)
+        .filter(|(_, normalized)| min_score.is_none_or(|min| *normalized >= min))
+        .take(limit)
+        .collect::<Vec<_>>();
 
     if json_output {
         let hits = hits
             .into_iter()
-            .map(|h| {
+            .map(|(h, normalized)| {
                 json!({
                     "score": h.score,
+                    "normalized_score": normalized,
                     "id": h.id,
                     "kind": h.kind.map(ix_core::entity::EntityKind::as_str),
                     "title": h.title,
@@ -436,31 +1239,71 @@ fn cmd_search(start: &Path, query: &str, limit: usize, json_output: bool) -> Res
         return Ok(());
     }
 
-    for hit in hits {
+    // Lead with the normalized score, which is what `--min-score` compares against.
+    for (hit, normalized) in hits {
         let kind = hit
             .kind
             .map_or("unknown", ix_core::entity::EntityKind::as_str);
-        println!("{:.3}\t{}\t{}\t{}", hit.score, hit.id, kind, hit.title);
+        println!(
+            "{normalized:.3}\t{:.3}\t{}\t{}\t{}",
+            hit.score, hit.id, kind, hit.title
+        );
     }
 
     Ok(())
 }
 
-fn cmd_graph(start: &Path, id: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
-    if json_output {
-        let graph = build_graph_json(&repo, id)?;
-        print_json(&graph)?;
-        return Ok(());
+fn cmd_graph(
+    repo_args: &RepoArgs,
+    id: Option<&str>,
+    all: bool,
+    format: GraphFormat,
+    context: Option<&Path>,
+) -> Result<()> {
+    let repo = repo_args.open()?;
+    let ids = if all {
+        repo.list(None, ix_core::repo::ListSort::default())?
+            .into_iter()
+            .map(|item| item.id)
+            .collect::<Vec<_>>()
+    } else {
+        let id = id.ok_or_else(|| anyhow::anyhow!("Provide an entity id or --all"))?;
+        vec![repo.resolve_id(id)?]
+    };
+
+    match format {
+        GraphFormat::Text => {
+            for (idx, id) in ids.iter().enumerate() {
+                if idx > 0 {
+                    println!();
+                }
+                print_graph(&repo, id)?;
+            }
+            Ok(())
+        }
+        GraphFormat::Json => {
+            if all {
+                let graphs = ids
+                    .iter()
+                    .map(|id| ix_app::graph::graph_json(&repo, id))
+                    .collect::<Result<Vec<_>>>()?;
+                print_json(&json!({ "graphs": graphs }))
+            } else {
+                print_json(&ix_app::graph::graph_json(&repo, &ids[0])?)
+            }
+        }
+        GraphFormat::JsonLd => {
+            let context = load_jsonld_context(&repo, context)?;
+            print_json(&build_graph_jsonld(&repo, &ids, &context)?)
+        }
     }
-
-    print_graph(&repo, id)
 }
 
-fn cmd_context(start: &Path, id: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_context(repo_args: &RepoArgs, id: &str, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let id = &repo.resolve_id(id)?;
     if json_output {
-        let context = build_context_json(&repo, id)?;
+        let context = ix_app::graph::context_json(&repo, id)?;
         print_json(&context)?;
         return Ok(());
     }
@@ -468,8 +1311,18 @@ fn cmd_context(start: &Path, id: &str, json_output: bool) -> Result<()> {
     print_context(&repo, id)
 }
 
-fn cmd_delete(start: &Path, id: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_delete(repo_args: &RepoArgs, id: &str, archive: bool, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    if archive {
+        let path = repo.archive_entity(id)?;
+        if json_output {
+            print_json(&json!({ "id": id, "archived": true, "path": path }))?;
+        } else {
+            println!("Archived {id} ({})", path.display());
+        }
+        return Ok(());
+    }
+
     repo.delete_entity(id)?;
     if json_output {
         print_json(&json!({ "id": id, "deleted": true }))?;
@@ -479,8 +1332,52 @@ fn cmd_delete(start: &Path, id: &str, json_output: bool) -> Result<()> {
     Ok(())
 }
 
-fn cmd_edit(start: &Path, id: &str, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_mv(
+    repo_args: &RepoArgs,
+    id: &str,
+    kind: ix_core::entity::EntityKind,
+    dry_run: bool,
+    json_output: bool,
+) -> Result<()> {
+    let repo = repo_args.open()?;
+    let report = repo.move_entity(id, kind, dry_run)?;
+    // The move changes ids and files, so refresh an index that has been synced before.
+    if !dry_run && ix_app::index_status(&repo)?.is_some() {
+        ix_app::sync(&repo).with_context(|| {
+            format!(
+                "Moved {} -> {}, but updating the index failed; run `ixchel sync`",
+                report.old_id, report.new_id
+            )
+        })?;
+    }
+    if json_output {
+        print_json(&json!({
+            "old_id": report.old_id,
+            "new_id": report.new_id,
+            "kind": report.kind.as_str(),
+            "path": report.new_path,
+            "rewritten": report.rewritten,
+            "dry_run": dry_run,
+        }))?;
+        return Ok(());
+    }
+
+    let verb = if dry_run { "Would move" } else { "Moved" };
+    println!(
+        "{verb} {} -> {} ({})",
+        report.old_id,
+        report.new_id,
+        report.new_path.display()
+    );
+    for referrer in &report.rewritten {
+        println!("  relink {referrer}");
+    }
+    Ok(())
+}
+
+fn cmd_edit(repo_args: &RepoArgs, id: &str, json_output: bool) -> Result<()> {
+    let repo = repo_args.open()?;
+    let id = &repo.resolve_id(id)?;
     let path = repo
         .paths
         .entity_path(id)
@@ -514,44 +1411,17 @@ fn print_json(value: &serde_json::Value) -> Result<()> {
     Ok(())
 }
 
-const METADATA_KEYS: &[&str] = &[
-    "id",
-    "type",
-    "title",
-    "status",
-    "date",
-    "created_at",
-    "updated_at",
-    "created_by",
-    "tags",
-];
-
 fn print_graph(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<()> {
-    let path = repo
-        .paths
-        .entity_path(id)
-        .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {id}"))?;
-    let raw = std::fs::read_to_string(&path)?;
-    let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
+    let graph = ix_app::graph::collect_graph(repo, id)?;
+    println!("{id}: {}", graph.title);
 
-    let title = ix_core::markdown::get_string(&doc.frontmatter, "title").unwrap_or_default();
-    println!("{id}: {title}");
-
-    for (rel, targets) in extract_relationships(&doc.frontmatter) {
+    for (rel, targets) in graph.outgoing {
         println!("{rel}:");
         for target in targets {
-            let target_title = repo
-                .paths
-                .entity_path(&target)
-                .and_then(|p| std::fs::read_to_string(&p).ok().map(|raw| (p, raw)))
-                .and_then(|(p, raw)| ix_core::markdown::parse_markdown(&p, &raw).ok())
-                .and_then(|d| ix_core::markdown::get_string(&d.frontmatter, "title"))
-                .unwrap_or_default();
-
-            if target_title.is_empty() {
-                println!("  - {target}");
-            } else {
-                println!("  - {target}: {target_title}");
+            let qualifiers = format_properties(&target.properties);
+            match target.title.title().filter(|title| !title.is_empty()) {
+                Some(title) => println!("  - {}: {title}{qualifiers}", target.id),
+                None => println!("  - {}{qualifiers}", target.id),
             }
         }
     }
@@ -560,36 +1430,12 @@ fn print_graph(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<()> {
 }
 
 fn print_context(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<()> {
-    let path = repo
-        .paths
-        .entity_path(id)
-        .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {id}"))?;
-    let raw = std::fs::read_to_string(&path)?;
-    let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-
-    let mut ids = vec![id.to_string()];
-    for (_, targets) in extract_relationships(&doc.frontmatter) {
-        ids.extend(targets);
-    }
-
-    ids.sort();
-    ids.dedup();
-
-    for entity_id in ids {
-        let path = repo
-            .paths
-            .entity_path(&entity_id)
-            .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {entity_id}"))?;
-        let raw = std::fs::read_to_string(&path)?;
-        let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-
-        let title = ix_core::markdown::get_string(&doc.frontmatter, "title").unwrap_or_default();
-
+    for item in ix_app::graph::collect_context(repo, id)? {
         println!("---");
-        println!("{entity_id}: {title}");
+        println!("{}: {}{}", item.id, item.title, format_edges(&item.edges));
         println!();
-        print!("{}", doc.body);
-        if !doc.body.ends_with('\n') {
+        print!("{}", item.body);
+        if !item.body.ends_with('\n') {
             println!();
         }
     }
@@ -597,96 +1443,142 @@ fn print_context(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<()> {
     Ok(())
 }
 
-fn build_graph_json(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<serde_json::Value> {
-    let (root_title, outgoing) = collect_graph(repo, id)?;
-    Ok(json!({
-        "id": id,
-        "title": root_title,
-        "outgoing": outgoing.into_iter().map(|(rel, targets)| {
-            json!({
-                "rel": rel,
-                "targets": targets.into_iter().map(|(id, title)| json!({ "id": id, "title": title })).collect::<Vec<_>>(),
-            })
-        }).collect::<Vec<_>>()
-    }))
-}
+const DEFAULT_JSONLD_VOCAB: &str = "https://kevinmichaelchen.github.io/ixchel/ns#";
 
-fn build_context_json(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<serde_json::Value> {
-    let items = collect_context(repo, id)?;
-    Ok(json!({
-        "id": id,
-        "items": items.into_iter().map(|(id, title, body)| json!({ "id": id, "title": title, "body": body })).collect::<Vec<_>>(),
-    }))
+fn default_jsonld_context() -> serde_json::Value {
+    json!({
+        "@vocab": DEFAULT_JSONLD_VOCAB,
+        "@base": "urn:ixchel:",
+        "title": "http://purl.org/dc/terms/title",
+    })
 }
 
-type GraphEdgeTarget = (String, Option<String>);
-type GraphOutgoing = Vec<(String, Vec<GraphEdgeTarget>)>;
-type CollectedGraph = (String, GraphOutgoing);
-
-fn collect_graph(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<CollectedGraph> {
-    let path = repo
-        .paths
-        .entity_path(id)
-        .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {id}"))?;
-    let raw = std::fs::read_to_string(&path)?;
-    let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-
-    let title = ix_core::markdown::get_string(&doc.frontmatter, "title").unwrap_or_default();
-    let mut outgoing = Vec::new();
-
-    for (rel, targets) in extract_relationships(&doc.frontmatter) {
-        let mut items = Vec::new();
-        for target in targets {
-            let target_title = repo
-                .paths
-                .entity_path(&target)
-                .and_then(|p| std::fs::read_to_string(&p).ok().map(|raw| (p, raw)))
-                .and_then(|(p, raw)| ix_core::markdown::parse_markdown(&p, &raw).ok())
-                .and_then(|d| ix_core::markdown::get_string(&d.frontmatter, "title"));
-            items.push((target, target_title));
+fn load_jsonld_context(
+    repo: &ix_core::repo::IxchelRepo,
+    override_path: Option<&Path>,
+) -> Result<serde_json::Value> {
+    let path = override_path.map(Path::to_path_buf).or_else(|| {
+        let path = repo.paths.ixchel_dir().join("context.jsonld");
+        path.exists().then_some(path)
+    });
+    let Some(path) = path else {
+        return Ok(default_jsonld_context());
+    };
+
+    let raw = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut value: serde_json::Value = serde_json::from_str(&raw)
+        .with_context(|| format!("Failed to parse JSON-LD context {}", path.display()))?;
+
+    // Accept either a bare context object or a document wrapping it in `@context`.
+    let user = value
+        .as_object_mut()
+        .and_then(|doc| doc.remove("@context"))
+        .unwrap_or(value);
+
+    // Layer the user's terms over the defaults so unnamed relations keep the default `@vocab`.
+    let mut context = default_jsonld_context();
+    match user {
+        serde_json::Value::Object(terms) => {
+            if let serde_json::Value::Object(defaults) = &mut context {
+                defaults.extend(terms);
+            }
+            Ok(context)
         }
-        outgoing.push((rel, items));
+        // Arrays and remote context IRIs are applied in order after the defaults.
+        other => Ok(json!([context, other])),
     }
+}
+
+fn jsonld_type(id: &str) -> Option<String> {
+    let kind = ix_core::entity::kind_from_id(id)?.as_str();
+    let mut chars = kind.chars();
+    let first = chars.next()?;
+    Some(first.to_ascii_uppercase().to_string() + chars.as_str())
+}
 
-    Ok((title, outgoing))
+fn jsonld_node(id: &str, title: Option<String>) -> serde_json::Map<String, serde_json::Value> {
+    let mut node = serde_json::Map::new();
+    node.insert("@id".to_string(), json!(id));
+    if let Some(kind) = jsonld_type(id) {
+        node.insert("@type".to_string(), json!(kind));
+    }
+    if let Some(title) = title.filter(|title| !title.is_empty()) {
+        node.insert("title".to_string(), json!(title));
+    }
+    node
 }
 
-fn collect_context(
+fn build_graph_jsonld(
     repo: &ix_core::repo::IxchelRepo,
-    id: &str,
-) -> Result<Vec<(String, String, String)>> {
-    let path = repo
-        .paths
-        .entity_path(id)
-        .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {id}"))?;
-    let raw = std::fs::read_to_string(&path)?;
-    let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
+    ids: &[String],
+    context: &serde_json::Value,
+) -> Result<serde_json::Value> {
+    let mut nodes: Vec<serde_json::Value> = Vec::new();
+    let mut targets_only = BTreeMap::new();
+    let roots = ids.iter().collect::<BTreeSet<_>>();
+
+    for id in ids {
+        let graph = ix_app::graph::collect_graph(repo, id)?;
+        let mut node = jsonld_node(id, Some(graph.title));
+        for (rel, targets) in graph.outgoing {
+            let refs = targets
+                .into_iter()
+                // JSON-LD has no standard place for per-edge data, so `target.properties`
+                // is only emitted by the JSON format.
+                .map(|target| {
+                    let reference = json!({ "@id": target.id });
+                    if !roots.contains(&target.id) {
+                        targets_only
+                            .entry(target.id)
+                            .or_insert_with(|| target.title.title());
+                    }
+                    reference
+                })
+                .collect::<Vec<_>>();
+            node.insert(rel, serde_json::Value::Array(refs));
+        }
+        nodes.push(serde_json::Value::Object(node));
+    }
 
-    let mut ids = vec![id.to_string()];
-    for (_, targets) in extract_relationships(&doc.frontmatter) {
-        ids.extend(targets);
+    for (target, title) in targets_only {
+        nodes.push(serde_json::Value::Object(jsonld_node(&target, title)));
     }
 
-    ids.sort();
-    ids.dedup();
+    Ok(json!({ "@context": context, "@graph": nodes }))
+}
 
-    let mut out = Vec::new();
-    for entity_id in ids {
-        let path = repo
-            .paths
-            .entity_path(&entity_id)
-            .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {entity_id}"))?;
-        let raw = std::fs::read_to_string(&path)?;
-        let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-        let title = ix_core::markdown::get_string(&doc.frontmatter, "title").unwrap_or_default();
-        out.push((entity_id, title, doc.body));
+/// Render edge qualifiers as ` [key: value, ...]`, or nothing for bare targets.
+fn format_properties(properties: &ix_app::graph::EdgeProperties) -> String {
+    if properties.is_empty() {
+        return String::new();
     }
+    let pairs = properties
+        .iter()
+        .map(|(key, value)| format!("{key}: {value}"))
+        .collect::<Vec<_>>();
+    format!(" [{}]", pairs.join(", "))
+}
 
-    Ok(out)
+/// Render the root's edges to a context item as ` (via rel [k: v], rel)`.
+fn format_edges(edges: &[ix_app::graph::ContextEdge]) -> String {
+    if edges.is_empty() {
+        return String::new();
+    }
+    let edges = edges
+        .iter()
+        .map(|edge| format!("{}{}", edge.rel, format_properties(&edge.properties)))
+        .collect::<Vec<_>>();
+    format!(" (via {})", edges.join(", "))
 }
 
-fn cmd_watch(start: &Path, unwatch: bool, foreground: bool, json_output: bool) -> Result<()> {
-    let repo = ix_core::repo::IxchelRepo::open_from(start)?;
+fn cmd_watch(
+    repo_args: &RepoArgs,
+    unwatch: bool,
+    foreground: bool,
+    json_output: bool,
+) -> Result<()> {
+    let repo = repo_args.open()?;
     let repo_root = repo.paths.repo_root().to_string_lossy().to_string();
 
     if foreground {
@@ -747,8 +1639,14 @@ fn cmd_watch(start: &Path, unwatch: bool, foreground: bool, json_output: bool) -
     Ok(())
 }
 
+enum WatchMessage {
+    Event(notify::Event),
+    Stop,
+}
+
 fn run_foreground_watcher(repo: &ix_core::repo::IxchelRepo, json_output: bool) -> Result<()> {
-    use notify::{Config, Event, RecursiveMode, Watcher};
+    use notify::{Config, RecursiveMode, Watcher};
+    use std::sync::mpsc::RecvTimeoutError;
 
     let ixchel_dir = repo.paths.ixchel_dir();
     if !ixchel_dir.exists() {
@@ -757,59 +1655,132 @@ fn run_foreground_watcher(repo: &ix_core::repo::IxchelRepo, json_output: bool) -
 
     let repo_root = repo.paths.repo_root().to_path_buf();
 
-    if !json_output {
-        println!("Watching {} (Ctrl+C to stop)", ixchel_dir.display());
-    }
-
-    // Create a channel for events
-    let (tx, rx) = std::sync::mpsc::channel::<Event>();
+    let (tx, rx) = std::sync::mpsc::channel::<WatchMessage>();
 
-    // Create watcher
+    let event_tx = tx.clone();
     let mut watcher = notify::RecommendedWatcher::new(
-        move |res: Result<Event, notify::Error>| {
+        move |res: Result<notify::Event, notify::Error>| {
             if let Ok(event) = res {
-                let _ = tx.send(event);
+                let _ = event_tx.send(WatchMessage::Event(event));
             }
         },
         Config::default(),
     )?;
-
     watcher.watch(&ixchel_dir, RecursiveMode::Recursive)?;
 
-    // Process events
-    for event in rx {
-        // Filter for .md files
-        let md_paths: Vec<_> = event
-            .paths
-            .iter()
-            .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("md"))
-            .filter(|p| !is_in_ignored_dir(&repo_root, p))
-            .collect();
-
-        if md_paths.is_empty() {
-            continue;
+    // Ctrl+C only requests a stop; a sync that is already running finishes first.
+    std::thread::spawn(move || {
+        let Ok(rt) = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+        else {
+            return;
+        };
+        if rt.block_on(tokio::signal::ctrl_c()).is_ok() {
+            let _ = tx.send(WatchMessage::Stop);
         }
+    });
 
-        for path in md_paths {
-            let relative = path.strip_prefix(&repo_root).unwrap_or(path);
-            let kind = match event.kind {
-                notify::EventKind::Create(_) => "create",
-                notify::EventKind::Modify(_) => "modify",
-                notify::EventKind::Remove(_) => "remove",
-                _ => continue,
-            };
+    if !json_output {
+        println!("Watching {} (Ctrl+C to stop)", ixchel_dir.display());
+    }
+    watch_sync(repo, &BTreeMap::new(), json_output)?;
 
-            if json_output {
-                print_json(&json!({
-                    "event": kind,
-                    "path": relative.to_string_lossy(),
-                }))?;
-            } else {
-                println!("{kind}: {}", relative.display());
+    let mut stopping = false;
+    while !stopping {
+        let Ok(first) = rx.recv() else {
+            break;
+        };
+
+        let mut changes = BTreeMap::new();
+        stopping = !collect_watch_change(&repo_root, first, &mut changes);
+        while !stopping {
+            match rx.recv_timeout(WATCH_DEBOUNCE) {
+                Ok(message) => stopping = !collect_watch_change(&repo_root, message, &mut changes),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => stopping = true,
             }
         }
+
+        if !changes.is_empty() {
+            watch_sync(repo, &changes, json_output)?;
+        }
+    }
+
+    if !json_output {
+        println!("Stopped watching {}", ixchel_dir.display());
+    }
+    Ok(())
+}
+
+/// Record the markdown paths touched by `message`; returns `false` once a stop is requested.
+fn collect_watch_change(
+    repo_root: &Path,
+    message: WatchMessage,
+    changes: &mut BTreeMap<PathBuf, &'static str>,
+) -> bool {
+    let WatchMessage::Event(event) = message else {
+        return false;
+    };
+    let kind = match event.kind {
+        notify::EventKind::Create(_) => "create",
+        notify::EventKind::Modify(_) => "modify",
+        notify::EventKind::Remove(_) => "remove",
+        _ => return true,
+    };
+
+    for path in event.paths {
+        if path.extension().and_then(|s| s.to_str()) != Some("md")
+            || is_in_ignored_dir(repo_root, &path)
+        {
+            continue;
+        }
+        let relative = path.strip_prefix(repo_root).unwrap_or(&path).to_path_buf();
+        changes.insert(relative, kind);
+    }
+    true
+}
+
+/// Sync the index after a batch of changes and print one summary for it.
+///
+/// Sync failures (often a half-written file) are reported without stopping the watcher.
+fn watch_sync(
+    repo: &ix_core::repo::IxchelRepo,
+    changes: &BTreeMap<PathBuf, &'static str>,
+    json_output: bool,
+) -> Result<()> {
+    let result = ix_app::sync(repo);
+
+    if json_output {
+        let changes = changes
+            .iter()
+            .map(|(path, kind)| json!({ "event": kind, "path": path.to_string_lossy() }))
+            .collect::<Vec<_>>();
+        let payload = match result {
+            Ok(stats) => json!({
+                "changes": changes,
+                "scanned": stats.scanned,
+                "added": stats.added,
+                "modified": stats.modified,
+                "deleted": stats.deleted,
+                "unchanged": stats.unchanged,
+            }),
+            Err(err) => json!({ "changes": changes, "error": format!("{err:#}") }),
+        };
+        println!("{}", serde_json::to_string(&payload)?);
+        return Ok(());
     }
 
+    for (path, kind) in changes {
+        println!("{kind}: {}", path.display());
+    }
+    match result {
+        Ok(stats) => println!(
+            "Synced: added={} modified={} deleted={} unchanged={}",
+            stats.added, stats.modified, stats.deleted, stats.unchanged
+        ),
+        Err(err) => eprintln!("Sync failed: {err:#}"),
+    }
     Ok(())
 }
 
@@ -834,42 +1805,11 @@ fn is_in_ignored_dir(repo_root: &Path, path: &Path) -> bool {
     false
 }
 
-fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<String>)> {
-    let mut rels = Vec::new();
-
-    for (key, value) in frontmatter {
-        let YamlValue::String(key) = key else {
-            continue;
-        };
-
-        if METADATA_KEYS.contains(&key.as_str()) {
-            continue;
-        }
-
-        let targets = match value {
-            YamlValue::Sequence(seq) => seq
-                .iter()
-                .filter_map(|v| match v {
-                    YamlValue::String(s) => Some(s.clone()),
-                    _ => None,
-                })
-                .collect::<Vec<_>>(),
-            YamlValue::String(s) => vec![s.clone()],
-            _ => Vec::new(),
-        };
-
-        let targets = targets
-            .into_iter()
-            .filter(|t| ix_core::entity::looks_like_entity_id(t))
-            .collect::<Vec<_>>();
-
-        if targets.is_empty() {
-            continue;
-        }
-
-        rels.push((key.clone(), targets));
-    }
-
+fn extract_relationships(
+    repo: &ix_core::repo::IxchelRepo,
+    frontmatter: &serde_yaml::Mapping,
+) -> Vec<(String, Vec<String>)> {
+    let mut rels = repo.extract_relationships(frontmatter);
     rels.sort_by(|a, b| a.0.cmp(&b.0));
     rels
 }
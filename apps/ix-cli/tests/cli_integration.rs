@@ -0,0 +1,752 @@
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+fn ixchel_command(root: &Path, home: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_ixchel"));
+    command
+        .arg("--repo")
+        .arg(root)
+        .env("IXCHEL_HOME", home)
+        .env_remove("IXCHEL_CONFIG");
+    command
+}
+
+fn run_ixchel(root: &Path, home: &Path, args: &[&str]) -> Output {
+    ixchel_command(root, home)
+        .args(args)
+        .arg("--json")
+        .output()
+        .expect("run ixchel")
+}
+
+fn ixchel(root: &Path, home: &Path, args: &[&str]) -> Value {
+    let output = run_ixchel(root, home, args);
+    assert!(
+        output.status.success(),
+        "ixchel {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("parse json output")
+}
+
+fn init_memory_repo() -> (TempDir, TempDir) {
+    let temp = TempDir::new().expect("create tempdir");
+    let home = TempDir::new().expect("create ixchel home");
+    std::fs::create_dir_all(temp.path().join(".git")).expect("create .git marker");
+
+    ixchel(temp.path(), home.path(), &["init"]);
+    std::fs::write(
+        temp.path().join(".ixchel/config.toml"),
+        "[storage]\nbackend = \"memory\"\n",
+    )
+    .expect("write config");
+
+    (temp, home)
+}
+
+#[test]
+fn sync_and_search_use_configured_memory_backend() {
+    let (temp, home) = init_memory_repo();
+
+    let created = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Adopt PostgreSQL"],
+    );
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "issue", "Fix login redirect"],
+    );
+
+    let stats = ixchel(temp.path(), home.path(), &["sync"]);
+    assert_eq!(stats["scanned"], 2, "{stats}");
+
+    let result = ixchel(temp.path(), home.path(), &["search", "postgresql"]);
+    let hits = result["hits"].as_array().expect("hits array");
+    assert_eq!(hits.len(), 1, "{result}");
+    assert_eq!(hits[0]["id"], created["id"]);
+    assert_eq!(hits[0]["kind"], "decision");
+}
+
+#[test]
+fn sync_writes_index_manifest_and_search_rejects_newer_schema() {
+    let (temp, home) = init_memory_repo();
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "idea", "Offline mode"],
+    );
+
+    let status = ixchel(temp.path(), home.path(), &["status"]);
+    assert_eq!(status["synced"], false, "{status}");
+
+    ixchel(temp.path(), home.path(), &["sync"]);
+    let status = ixchel(temp.path(), home.path(), &["status"]);
+    assert_eq!(status["synced"], true, "{status}");
+    assert_eq!(status["manifest"]["backend"], "memory");
+    assert_eq!(status["manifest"]["entity_count"], 1);
+    assert_eq!(status["manifest"]["kind_counts"]["idea"], 1);
+
+    let manifest_path = temp.path().join(".ixchel/data/index-manifest.json");
+    let raw = std::fs::read_to_string(&manifest_path).expect("read manifest");
+    let mut manifest: Value = serde_json::from_str(&raw).expect("parse manifest");
+    manifest["schema_version"] = Value::from(999);
+    std::fs::write(&manifest_path, manifest.to_string()).expect("write manifest");
+
+    let output = run_ixchel(temp.path(), home.path(), &["search", "offline"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("newer than this binary supports"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn graph_emits_json_ld_with_configurable_context() {
+    let (temp, home) = init_memory_repo();
+    let decision = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Use JSON-LD"],
+    );
+    let issue = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "issue", "Publish graph"],
+    );
+    let decision_id = decision["id"].as_str().expect("decision id");
+    let issue_id = issue["id"].as_str().expect("issue id");
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["link", issue_id, "implements", decision_id],
+    );
+
+    let doc = ixchel(
+        temp.path(),
+        home.path(),
+        &["graph", issue_id, "--format", "jsonld"],
+    );
+    assert!(doc["@context"]["@vocab"].is_string(), "{doc}");
+    let graph = doc["@graph"].as_array().expect("graph array");
+    assert_eq!(graph.len(), 2, "{doc}");
+    assert_eq!(graph[0]["@id"], issue_id);
+    assert_eq!(graph[0]["@type"], "Issue");
+    assert_eq!(graph[0]["implements"][0]["@id"], decision_id);
+    assert_eq!(graph[1]["@type"], "Decision");
+
+    let context_path = temp.path().join("context.json");
+    std::fs::write(
+        &context_path,
+        r#"{ "@context": { "implements": "https://example.com/implements" } }"#,
+    )
+    .expect("write context");
+    let doc = ixchel(
+        temp.path(),
+        home.path(),
+        &[
+            "graph",
+            "--all",
+            "--format",
+            "jsonld",
+            "--context",
+            context_path.to_str().expect("utf-8 path"),
+        ],
+    );
+    assert_eq!(
+        doc["@context"]["implements"],
+        "https://example.com/implements"
+    );
+    // The partial context has no `@vocab`, so the default one still covers other relations.
+    assert!(doc["@context"]["@vocab"].is_string(), "{doc}");
+    assert_eq!(doc["@context"]["title"], "http://purl.org/dc/terms/title");
+    assert_eq!(doc["@graph"].as_array().expect("graph array").len(), 2);
+}
+
+#[test]
+fn graph_and_context_keep_qualifiers_per_edge() {
+    let (temp, home) = init_memory_repo();
+    let decision = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Use Postgres"],
+    );
+    let issue = ixchel(temp.path(), home.path(), &["create", "issue", "Migrate"]);
+    let decision_id = decision["id"].as_str().expect("decision id");
+    let issue_id = issue["id"].as_str().expect("issue id");
+    let issue_path = temp.path().join(issue["path"].as_str().expect("path"));
+    let raw = std::fs::read_to_string(&issue_path).expect("read issue");
+    let rels = format!(
+        "---\nimplements:\n- id: {decision_id}\n  since: 2026-01-01\ncites:\n- {decision_id}\n"
+    );
+    std::fs::write(&issue_path, raw.replacen("---\n", &rels, 1)).expect("write issue");
+
+    let graph = ixchel(temp.path(), home.path(), &["graph", issue_id]);
+    let outgoing = graph["outgoing"].as_array().expect("outgoing array");
+    assert_eq!(outgoing[0]["rel"], "cites", "{graph}");
+    assert!(outgoing[0]["targets"][0]["properties"].is_null(), "{graph}");
+    assert_eq!(outgoing[1]["rel"], "implements", "{graph}");
+    assert_eq!(
+        outgoing[1]["targets"][0]["properties"]["since"],
+        "2026-01-01"
+    );
+
+    let context = ixchel(temp.path(), home.path(), &["context", issue_id]);
+    let item = context["items"]
+        .as_array()
+        .expect("items array")
+        .iter()
+        .find(|item| item["id"] == decision_id)
+        .expect("decision item");
+    assert_eq!(
+        item["edges"],
+        serde_json::json!([
+            { "rel": "cites" },
+            { "rel": "implements", "properties": { "since": "2026-01-01" } },
+        ]),
+        "{context}"
+    );
+
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["context", issue_id])
+        .output()
+        .expect("run ixchel");
+    assert!(output.status.success());
+    let text = String::from_utf8(output.stdout).expect("utf8 output");
+    assert!(
+        text.contains(&format!(
+            "{decision_id}: Use Postgres (via cites, implements [since: 2026-01-01])"
+        )),
+        "{text}"
+    );
+
+    let doc = ixchel(
+        temp.path(),
+        home.path(),
+        &["graph", issue_id, "--format", "jsonld"],
+    );
+    assert_eq!(
+        doc["@graph"][0]["implements"][0],
+        serde_json::json!({ "@id": decision_id }),
+        "{doc}"
+    );
+}
+
+#[test]
+fn config_flag_and_env_override_project_config() {
+    let (temp, home) = init_memory_repo();
+    std::fs::write(
+        temp.path().join(".ixchel/config.toml"),
+        "[storage]\nbackend = \"bogus\"\n",
+    )
+    .expect("write project config");
+    let override_path = home.path().join("ci.toml");
+    std::fs::write(&override_path, "[storage]\nbackend = \"memory\"\n")
+        .expect("write override config");
+    let override_arg = override_path.to_str().expect("utf-8 path");
+
+    let output = run_ixchel(temp.path(), home.path(), &["sync"]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("bogus"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stats = ixchel(
+        temp.path(),
+        home.path(),
+        &["sync", "--config", override_arg],
+    );
+    assert_eq!(stats["scanned"], 0, "{stats}");
+
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["sync", "--json"])
+        .env("IXCHEL_CONFIG", &override_path)
+        .output()
+        .expect("run ixchel");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The daemon loads its own config, so a client-side override is rejected.
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["sync", "--daemon", "--json"])
+        .env("IXCHEL_CONFIG", &override_path)
+        .output()
+        .expect("run ixchel");
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("IXCHEL_CONFIG cannot be forwarded"),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let missing = home.path().join("missing.toml");
+    let output = run_ixchel(
+        temp.path(),
+        home.path(),
+        &["sync", "--config", missing.to_str().expect("utf-8 path")],
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing.toml"));
+}
+
+#[test]
+fn create_print_emits_bare_path_or_id() {
+    let (temp, home) = init_memory_repo();
+
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["create", "decision", "Adopt PostgreSQL", "--print", "path"])
+        .output()
+        .expect("run ixchel");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("utf-8 stdout");
+    let path = Path::new(stdout.trim_end());
+    assert!(path.is_file(), "{stdout:?}");
+    assert_eq!(stdout.lines().count(), 1, "{stdout:?}");
+
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["create", "issue", "Fix login", "--print", "id"])
+        .output()
+        .expect("run ixchel");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("utf-8 stdout");
+    assert!(stdout.trim_end().starts_with("iss-"), "{stdout:?}");
+    assert_eq!(stdout.lines().count(), 1, "{stdout:?}");
+}
+
+#[test]
+fn show_resolve_links_annotates_targets() {
+    let (temp, home) = init_memory_repo();
+    let decision = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Use Postgres"],
+    );
+    let issue = ixchel(temp.path(), home.path(), &["create", "issue", "Migrate"]);
+    let decision_id = decision["id"].as_str().expect("decision id");
+    let issue_id = issue["id"].as_str().expect("issue id");
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["link", issue_id, "implements", decision_id],
+    );
+    let output = run_ixchel(
+        temp.path(),
+        home.path(),
+        &["link", issue_id, "blocks", "iss-deadbe"],
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Pass --allow-dangling"), "{stderr}");
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["link", issue_id, "blocks", "iss-deadbe", "--allow-dangling"],
+    );
+
+    let untitled = ixchel(temp.path(), home.path(), &["create", "decision", "Draft"]);
+    let untitled_id = untitled["id"].as_str().expect("untitled id");
+    let untitled_path = temp.path().join(untitled["path"].as_str().expect("path"));
+    let raw = std::fs::read_to_string(&untitled_path).expect("read decision");
+    std::fs::write(&untitled_path, raw.replace("title: Draft\n", "")).expect("write decision");
+    let issue_path = temp.path().join(issue["path"].as_str().expect("path"));
+    let raw = std::fs::read_to_string(&issue_path).expect("read issue");
+    let qualified = format!("---\ncites:\n- id: {untitled_id}\n  note: early draft\n");
+    std::fs::write(&issue_path, raw.replacen("---\n", &qualified, 1)).expect("write issue");
+
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["show", issue_id, "--resolve-links"])
+        .output()
+        .expect("run ixchel");
+    assert!(output.status.success());
+    let text = String::from_utf8(output.stdout).expect("utf8 output");
+    assert!(
+        text.contains(&format!("- {decision_id} # \"Use Postgres\"")),
+        "{text}"
+    );
+    assert!(text.contains("- iss-deadbe # (missing)"), "{text}");
+    assert!(
+        text.contains(&format!(
+            "- id: {untitled_id} # (untitled)\n  note: early draft\n"
+        )),
+        "{text}"
+    );
+    assert!(text.contains("title: Migrate\n"), "{text}");
+
+    let result = ixchel(
+        temp.path(),
+        home.path(),
+        &["show", issue_id, "--resolve-links"],
+    );
+    let implements = &result["frontmatter"]["implements"][0];
+    assert_eq!(implements["id"], decision_id, "{result}");
+    assert_eq!(implements["title"], "Use Postgres");
+    assert!(result["frontmatter"]["blocks"][0]["title"].is_null());
+    assert_eq!(result["frontmatter"]["blocks"][0]["exists"], false);
+    let cites = &result["frontmatter"]["cites"][0];
+    assert!(cites["title"].is_null(), "{result}");
+    assert_eq!(cites["exists"], true);
+}
+
+#[test]
+fn link_from_file_reports_added_skipped_and_bad_lines() {
+    let (temp, home) = init_memory_repo();
+    let decision = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Use Postgres"],
+    );
+    let issue = ixchel(temp.path(), home.path(), &["create", "issue", "Migrate"]);
+    let decision_id = decision["id"].as_str().expect("decision id");
+    let issue_id = issue["id"].as_str().expect("issue id");
+
+    let links = temp.path().join("links.tsv");
+    std::fs::write(
+        &links,
+        format!(
+            "# from\trel\tto\n{issue_id}\timplements\t{decision_id}\n{issue_id}\timplements\t{decision_id}\nnot a link\n"
+        ),
+    )
+    .expect("write links");
+    let links = links.to_str().expect("utf8 path");
+
+    let output = run_ixchel(
+        temp.path(),
+        home.path(),
+        &["link", "--from-file", links, "--strict"],
+    );
+    assert!(!output.status.success());
+
+    let output = run_ixchel(temp.path(), home.path(), &["link", "--from-file", links]);
+    assert!(!output.status.success());
+    let result: Value = serde_json::from_slice(&output.stdout).expect("parse json output");
+    assert_eq!(result["added"], 1, "{result}");
+    assert_eq!(result["skipped"], 1, "{result}");
+    assert_eq!(result["errors"][0]["line"], 4, "{result}");
+
+    let graph = ixchel(temp.path(), home.path(), &["graph", issue_id]);
+    assert!(graph.to_string().contains(decision_id), "{graph}");
+}
+
+#[test]
+fn doctor_reports_checks_and_fails_on_dangling_links() {
+    let (temp, home) = init_memory_repo();
+    let issue = ixchel(temp.path(), home.path(), &["create", "issue", "Fix login"]);
+
+    let report = ixchel(temp.path(), home.path(), &["doctor"]);
+    assert_eq!(report["ok"], true, "{report}");
+    let status_of = |report: &Value, name: &str| {
+        report["checks"]
+            .as_array()
+            .expect("checks array")
+            .iter()
+            .find(|c| c["name"] == name)
+            .map(|c| c["status"].clone())
+    };
+    assert_eq!(status_of(&report, "index schema"), Some("warn".into()));
+    assert_eq!(status_of(&report, "embedder"), None, "{report}");
+
+    ixchel(temp.path(), home.path(), &["sync"]);
+    let issue_id = issue["id"].as_str().expect("issue id");
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["link", issue_id, "blocks", "iss-deadbe", "--allow-dangling"],
+    );
+
+    let output = run_ixchel(temp.path(), home.path(), &["doctor"]);
+    assert!(!output.status.success());
+    let report: Value = serde_json::from_slice(&output.stdout).expect("parse json output");
+    assert_eq!(status_of(&report, "links"), Some("fail".into()), "{report}");
+    assert_eq!(status_of(&report, "index"), Some("pass".into()), "{report}");
+    let links = report["checks"]
+        .as_array()
+        .expect("checks array")
+        .iter()
+        .find(|c| c["name"] == "links")
+        .expect("links check");
+    assert_eq!(links["detail"], "1 dangling link(s)", "{report}");
+}
+
+#[test]
+fn mv_refreshes_a_synced_index() {
+    let (temp, home) = init_memory_repo();
+    let idea = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "idea", "Offline mode"],
+    );
+    let idea_id = idea["id"].as_str().expect("idea id");
+    ixchel(temp.path(), home.path(), &["sync"]);
+
+    let moved = ixchel(
+        temp.path(),
+        home.path(),
+        &["mv", idea_id, "--kind", "decision"],
+    );
+    let new_id = moved["new_id"].as_str().expect("new id");
+
+    let status = ixchel(temp.path(), home.path(), &["status"]);
+    assert_eq!(status["manifest"]["kind_counts"]["decision"], 1, "{status}");
+    assert!(
+        status["manifest"]["kind_counts"].get("idea").is_none(),
+        "{status}"
+    );
+
+    let hits = ixchel(temp.path(), home.path(), &["search", "offline"]);
+    let ids = hits["hits"]
+        .as_array()
+        .expect("hits array")
+        .iter()
+        .map(|hit| hit["id"].as_str().expect("hit id"))
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [new_id], "{hits}");
+}
+
+#[test]
+fn doctor_fails_outside_a_git_repo() {
+    let temp = TempDir::new().expect("create tempdir");
+    let home = TempDir::new().expect("create ixchel home");
+
+    let output = run_ixchel(temp.path(), home.path(), &["doctor"]);
+    assert!(!output.status.success());
+    let report: Value = serde_json::from_slice(&output.stdout).expect("parse json output");
+    let checks = report["checks"].as_array().expect("checks array");
+    assert_eq!(checks.len(), 1, "{report}");
+    assert_eq!(checks[0]["name"], "git", "{report}");
+    assert_eq!(checks[0]["status"], "fail", "{report}");
+    assert_eq!(report["ok"], false, "{report}");
+}
+
+#[test]
+fn gc_rebuilds_index_and_is_idempotent() {
+    let (temp, home) = init_memory_repo();
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Adopt PostgreSQL"],
+    );
+    let issue = ixchel(temp.path(), home.path(), &["create", "issue", "Fix login"]);
+    ixchel(temp.path(), home.path(), &["sync"]);
+
+    let issue_id = issue["id"].as_str().expect("issue id");
+    ixchel(temp.path(), home.path(), &["delete", issue_id]);
+
+    let report = ixchel(temp.path(), home.path(), &["gc"]);
+    assert_eq!(report["entities_before"], 2, "{report}");
+    assert_eq!(report["entities_after"], 1, "{report}");
+
+    let report = ixchel(temp.path(), home.path(), &["gc", "--reembed"]);
+    assert_eq!(report["entities_before"], 1, "{report}");
+    assert_eq!(report["entities_after"], 1, "{report}");
+
+    let status = ixchel(temp.path(), home.path(), &["status"]);
+    assert_eq!(status["manifest"]["entity_count"], 1, "{status}");
+}
+
+#[test]
+fn completions_cover_global_flags_subcommands_and_kinds() {
+    let home = TempDir::new().expect("create home");
+    let output = ixchel_command(home.path(), home.path())
+        .args(["completions", "bash"])
+        .output()
+        .expect("run ixchel");
+    assert!(output.status.success());
+    let script = String::from_utf8(output.stdout).expect("utf8 output");
+    for needle in [
+        "--repo",
+        "--json",
+        "validate-schema",
+        "completions",
+        "decision",
+        "citation",
+    ] {
+        assert!(script.contains(needle), "missing {needle}");
+    }
+
+    let (temp, home) = init_memory_repo();
+    let created = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decisions", "Plural kind"],
+    );
+    assert_eq!(created["kind"], "decision", "{created}");
+}
+
+#[test]
+fn search_min_score_filters_on_normalized_scores() {
+    let (temp, home) = init_memory_repo();
+    let best = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Adopt PostgreSQL"],
+    );
+    ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "issue", "Tune PostgreSQL vacuum"],
+    );
+    ixchel(temp.path(), home.path(), &["sync"]);
+
+    let result = ixchel(temp.path(), home.path(), &["search", "adopt postgresql"]);
+    let hits = result["hits"].as_array().expect("hits array");
+    assert_eq!(hits.len(), 2, "{result}");
+    assert_eq!(hits[0]["normalized_score"], 1.0);
+    assert_eq!(hits[1]["normalized_score"], 0.0);
+    assert_eq!(hits[1]["score"], 0.5);
+
+    let result = ixchel(
+        temp.path(),
+        home.path(),
+        &["search", "adopt postgresql", "--min-score", "0.5"],
+    );
+    let hits = result["hits"].as_array().expect("hits array");
+    assert_eq!(hits.len(), 1, "{result}");
+    assert_eq!(hits[0]["id"], best["id"]);
+
+    let output = ixchel_command(temp.path(), home.path())
+        .args(["search", "adopt postgresql"])
+        .output()
+        .expect("run ixchel");
+    let stdout = String::from_utf8(output.stdout).expect("utf-8 stdout");
+    let columns = stdout
+        .lines()
+        .map(|line| line.split('\t').take(2).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        [["1.000", "1.000"], ["0.000", "0.500"]],
+        "{stdout}"
+    );
+
+    let output = run_ixchel(
+        temp.path(),
+        home.path(),
+        &["search", "postgresql", "--min-score", "1.5"],
+    );
+    assert!(!output.status.success());
+}
+
+#[test]
+fn search_min_score_normalizes_before_limit() {
+    let (temp, home) = init_memory_repo();
+    for title in [
+        "Adopt PostgreSQL replicas",
+        "Tune PostgreSQL replicas",
+        "Tune PostgreSQL vacuum",
+    ] {
+        ixchel(temp.path(), home.path(), &["create", "issue", title]);
+    }
+    ixchel(temp.path(), home.path(), &["sync"]);
+
+    let result = ixchel(
+        temp.path(),
+        home.path(),
+        &[
+            "search",
+            "adopt postgresql replicas",
+            "--limit",
+            "2",
+            "--min-score",
+            "0.4",
+        ],
+    );
+    let hits = result["hits"].as_array().expect("hits array");
+    assert_eq!(hits.len(), 2, "{result}");
+    assert_eq!(hits[0]["normalized_score"], 1.0);
+    assert_eq!(hits[1]["title"], "Tune PostgreSQL replicas");
+    let second = hits[1]["normalized_score"]
+        .as_f64()
+        .expect("normalized score");
+    assert!((second - 0.5).abs() < 1e-6, "{result}");
+}
+
+#[test]
+fn search_reads_query_from_stdin() {
+    let (temp, home) = init_memory_repo();
+    let created = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "decision", "Adopt PostgreSQL"],
+    );
+    ixchel(temp.path(), home.path(), &["sync"]);
+
+    let mut child = ixchel_command(temp.path(), home.path())
+        .args(["search", "-", "--json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("spawn ixchel");
+    child
+        .stdin
+        .take()
+        .expect("stdin")
+        .write_all(b"adopt\npostgresql\n")
+        .expect("write query");
+    let output = child.wait_with_output().expect("wait for ixchel");
+    assert!(output.status.success());
+    let result: Value = serde_json::from_slice(&output.stdout).expect("parse json output");
+    assert_eq!(result["hits"][0]["id"], created["id"], "{result}");
+
+    let output = run_ixchel(
+        temp.path(),
+        home.path(),
+        &["search", "postgresql", "--query-file", "-"],
+    );
+    assert!(!output.status.success());
+}
+
+#[test]
+fn watch_foreground_resyncs_changes_and_stops_on_interrupt() {
+    use std::io::{BufRead, BufReader};
+
+    let (temp, home) = init_memory_repo();
+    let mut child = ixchel_command(temp.path(), home.path())
+        .args(["--json", "watch", "--foreground"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn watch");
+    let mut lines = BufReader::new(child.stdout.take().expect("stdout")).lines();
+    let mut next_batch = || -> Value {
+        let line = lines.next().expect("watch output").expect("read line");
+        serde_json::from_str(&line).expect("parse watch output")
+    };
+
+    let initial = next_batch();
+    assert_eq!(initial["changes"], serde_json::json!([]));
+
+    let created = ixchel(
+        temp.path(),
+        home.path(),
+        &["create", "issue", "Watched issue"],
+    );
+    let id = created["id"].as_str().expect("id");
+    let batch = next_batch();
+    let paths = batch["changes"]
+        .as_array()
+        .expect("changes")
+        .iter()
+        .map(|change| change["path"].as_str().unwrap_or_default().to_string())
+        .collect::<Vec<_>>();
+    assert!(paths.iter().any(|path| path.contains(id)), "{batch}");
+    assert_eq!(batch["scanned"], 1, "{batch}");
+
+    let status = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .expect("send SIGINT");
+    assert!(status.success());
+    let status = child.wait().expect("wait for watch");
+    assert!(status.success(), "{status:?}");
+}
@@ -1,6 +1,6 @@
 use crate::{
     Command, DEFAULT_SOCKET_PATH, DaemonError, EnqueueSyncPayload, Request, Response,
-    ResponseResult, SyncState, UnwatchPayload, WaitSyncPayload, WatchPayload,
+    ResponseResult, SyncState, UnwatchPayload, WaitSyncPayload, WaitSyncResponse, WatchPayload,
 };
 use std::path::Path;
 use std::process::Stdio;
@@ -163,6 +163,19 @@ impl Client {
         sync_id: &str,
         timeout_ms: u64,
     ) -> Result<SyncState, DaemonError> {
+        self.wait_sync_response(repo_root, tool, sync_id, timeout_ms)
+            .await
+            .map(|wait| wait.state)
+    }
+
+    /// Wait for a sync and return its final state along with stats or the failure message.
+    pub async fn wait_sync_response(
+        &self,
+        repo_root: &str,
+        tool: &str,
+        sync_id: &str,
+        timeout_ms: u64,
+    ) -> Result<WaitSyncResponse, DaemonError> {
         let request = Request::new(
             repo_root,
             tool,
@@ -176,7 +189,7 @@ impl Client {
         match response.result {
             ResponseResult::Ok { payload } => {
                 if let crate::ResponsePayload::WaitSync(wait) = payload {
-                    Ok(wait.state)
+                    Ok(wait)
                 } else {
                     Err(DaemonError::Internal("Unexpected response type".into()))
                 }
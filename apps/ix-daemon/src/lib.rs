@@ -293,6 +293,9 @@ pub struct WaitSyncResponse {
     pub state: SyncState,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stats: Option<SyncStats>,
+    /// Failure message when `state` is `error`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -309,6 +312,14 @@ pub struct SyncStats {
     pub files_scanned: u64,
     pub files_updated: u64,
     pub duration_ms: u64,
+    #[serde(default)]
+    pub files_added: u64,
+    #[serde(default)]
+    pub files_modified: u64,
+    #[serde(default)]
+    pub files_deleted: u64,
+    #[serde(default)]
+    pub files_unchanged: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -412,6 +423,16 @@ mod tests {
         assert_eq!(parsed.tool, "issues");
     }
 
+    #[test]
+    fn test_wait_sync_response_accepts_legacy_stats() {
+        let json = r#"{"sync_id":"sync-1","state":"done","stats":{"files_scanned":3,"files_updated":1,"duration_ms":5}}"#;
+        let parsed: WaitSyncResponse = serde_json::from_str(json).unwrap();
+        let stats = parsed.stats.unwrap();
+        assert_eq!(stats.files_scanned, 3);
+        assert_eq!(stats.files_added, 0);
+        assert!(parsed.error.is_none());
+    }
+
     #[test]
     fn test_sync_state_values() {
         assert_eq!(
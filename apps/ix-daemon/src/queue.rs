@@ -348,6 +348,7 @@ mod tests {
                     files_scanned: 10,
                     files_updated: 2,
                     duration_ms: 100,
+                    ..SyncStats::default()
                 },
             )
             .await;
@@ -315,13 +315,18 @@ async fn handle_command(
 
             match queue.wait(sync_id, timeout).await {
                 Some(final_state) => {
-                    let job_stats = queue.get(sync_id).await.and_then(|j| j.stats);
+                    let (job_stats, job_error) = queue
+                        .get(sync_id)
+                        .await
+                        .map(|j| (j.stats, j.error))
+                        .unwrap_or_default();
                     Response::ok(
                         &req.id,
                         ResponsePayload::WaitSync(WaitSyncResponse {
                             sync_id: sync_id.clone(),
                             state: final_state,
                             stats: job_stats,
+                            error: job_error,
                         }),
                     )
                 }
@@ -70,6 +70,10 @@ impl SyncWorker {
                     files_scanned: u64::from(core_stats.scanned),
                     files_updated: u64::from(core_stats.added + core_stats.modified),
                     duration_ms,
+                    files_added: u64::from(core_stats.added),
+                    files_modified: u64::from(core_stats.modified),
+                    files_deleted: u64::from(core_stats.deleted),
+                    files_unchanged: u64::from(core_stats.unchanged),
                 };
                 tracing::info!(
                     "Sync job {} completed: scanned={}, added={}, modified={}, deleted={}, unchanged={}, duration={}ms",
@@ -295,7 +295,8 @@ fn tool_graph(args: &Value) -> Result<Value> {
         .ok_or_else(|| anyhow::anyhow!("ixchel_graph missing arguments.id"))?;
 
     let repo = ix_core::repo::IxchelRepo::open_from(&repo_path)?;
-    let graph = build_graph_json(&repo, id)?;
+    let id = repo.resolve_id(id)?;
+    let graph = ix_app::graph::graph_json(&repo, &id)?;
 
     tool_text(&graph)
 }
@@ -308,7 +309,8 @@ fn tool_context(args: &Value) -> Result<Value> {
         .ok_or_else(|| anyhow::anyhow!("ixchel_context missing arguments.id"))?;
 
     let repo = ix_core::repo::IxchelRepo::open_from(&repo_path)?;
-    let context = build_context_json(&repo, id)?;
+    let id = repo.resolve_id(id)?;
+    let context = ix_app::graph::context_json(&repo, &id)?;
 
     tool_text(&context)
 }
@@ -352,7 +354,7 @@ fn tool_tags(args: &Value) -> Result<Value> {
         .into_iter()
         .map(|(tag, ids)| (tag, ids.len()))
         .collect::<Vec<_>>();
-    items.sort_by(|a, b| a.0.cmp(&b.0));
+    items.sort_by_cached_key(|(tag, _)| (tag.to_lowercase(), tag.clone()));
 
     let tags = items
         .iter()
@@ -369,142 +371,49 @@ fn tool_text(payload: &Value) -> Result<Value> {
     }))
 }
 
-const METADATA_KEYS: &[&str] = &[
-    "id",
-    "type",
-    "title",
-    "status",
-    "date",
-    "created_at",
-    "updated_at",
-    "created_by",
-    "tags",
-];
-
-fn build_graph_json(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<Value> {
-    let (root_title, outgoing) = collect_graph(repo, id)?;
-    Ok(json!({
-        "id": id,
-        "title": root_title,
-        "outgoing": outgoing.into_iter().map(|(rel, targets)| {
-            json!({
-                "rel": rel,
-                "targets": targets.into_iter().map(|(id, title)| json!({ "id": id, "title": title })).collect::<Vec<_>>(),
-            })
-        }).collect::<Vec<_>>()
-    }))
-}
-
-fn build_context_json(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<Value> {
-    let items = collect_context(repo, id)?;
-    Ok(json!({
-        "id": id,
-        "items": items.into_iter().map(|(id, title, body)| json!({ "id": id, "title": title, "body": body })).collect::<Vec<_>>(),
-    }))
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-type GraphEdgeTarget = (String, Option<String>);
-type GraphOutgoing = Vec<(String, Vec<GraphEdgeTarget>)>;
-type CollectedGraph = (String, GraphOutgoing);
-
-fn collect_graph(repo: &ix_core::repo::IxchelRepo, id: &str) -> Result<CollectedGraph> {
-    let path = repo
-        .paths
-        .entity_path(id)
-        .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {id}"))?;
-    let raw = std::fs::read_to_string(&path)?;
-    let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-
-    let title = ix_core::markdown::get_string(&doc.frontmatter, "title").unwrap_or_default();
-    let mut outgoing = Vec::new();
-
-    for (rel, targets) in extract_relationships(&doc.frontmatter) {
-        let mut items = Vec::new();
-        for target in targets {
-            let target_title = repo
-                .paths
-                .entity_path(&target)
-                .and_then(|p| std::fs::read_to_string(&p).ok().map(|raw| (p, raw)))
-                .and_then(|(p, raw)| ix_core::markdown::parse_markdown(&p, &raw).ok())
-                .and_then(|d| ix_core::markdown::get_string(&d.frontmatter, "title"));
-            items.push((target, target_title));
-        }
-        outgoing.push((rel, items));
-    }
-
-    Ok((title, outgoing))
-}
-
-fn collect_context(
-    repo: &ix_core::repo::IxchelRepo,
-    id: &str,
-) -> Result<Vec<(String, String, String)>> {
-    let path = repo
-        .paths
-        .entity_path(id)
-        .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {id}"))?;
-    let raw = std::fs::read_to_string(&path)?;
-    let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-
-    let mut ids = vec![id.to_string()];
-    for (_, targets) in extract_relationships(&doc.frontmatter) {
-        ids.extend(targets);
+    fn tool_json(name: &str, arguments: &Value) -> Value {
+        let result = handle_tools_call(Some(json!({ "name": name, "arguments": arguments })))
+            .expect("tool call");
+        let text = result["content"][0]["text"].as_str().expect("text content");
+        serde_json::from_str(text).expect("tool json")
     }
 
-    ids.sort();
-    ids.dedup();
-
-    let mut out = Vec::new();
-    for entity_id in ids {
-        let path = repo
-            .paths
-            .entity_path(&entity_id)
-            .ok_or_else(|| anyhow::anyhow!("Unknown entity id prefix: {entity_id}"))?;
-        let raw = std::fs::read_to_string(&path)?;
-        let doc = ix_core::markdown::parse_markdown(&path, &raw)?;
-        let title = ix_core::markdown::get_string(&doc.frontmatter, "title").unwrap_or_default();
-        out.push((entity_id, title, doc.body));
+    #[test]
+    fn graph_and_context_resolve_aliases() {
+        let temp = tempfile::TempDir::new().expect("create tempdir");
+        std::fs::create_dir_all(temp.path().join(".git")).expect("create .git marker");
+        let repo = ix_core::repo::IxchelRepo::init_from(temp.path(), false).expect("init repo");
+        let decision = repo
+            .create_entity(ix_core::entity::EntityKind::Decision, "Decision A", None)
+            .expect("create decision");
+        let issue = repo
+            .create_entity(ix_core::entity::EntityKind::Issue, "Issue A", None)
+            .expect("create issue");
+        repo.link(&decision.id, "relates_to", &issue.id)
+            .expect("link");
+
+        let path = repo.paths.entity_path(&decision.id).expect("entity path");
+        let raw = std::fs::read_to_string(&path).expect("read entity");
+        let raw = raw.replacen("---\n", "---\naliases:\n- ADR-0007\n", 1);
+        std::fs::write(&path, raw).expect("write entity");
+
+        let repo_arg = temp.path().to_string_lossy().to_string();
+        let graph = tool_json(
+            "ixchel_graph",
+            &json!({ "repo": repo_arg, "id": "ADR-0007" }),
+        );
+        assert_eq!(graph["id"], decision.id.as_str());
+        assert_eq!(graph["outgoing"][0]["targets"][0]["id"], issue.id.as_str());
+
+        let context = tool_json(
+            "ixchel_context",
+            &json!({ "repo": repo_arg, "id": "adr-0007" }),
+        );
+        assert_eq!(context["id"], decision.id.as_str());
+        assert_eq!(context["items"].as_array().expect("items").len(), 2);
     }
-
-    Ok(out)
-}
-
-fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<String>)> {
-    let mut rels = Vec::new();
-
-    for (key, value) in frontmatter {
-        let serde_yaml::Value::String(key) = key else {
-            continue;
-        };
-
-        if METADATA_KEYS.contains(&key.as_str()) {
-            continue;
-        }
-
-        let targets = match value {
-            serde_yaml::Value::Sequence(seq) => seq
-                .iter()
-                .filter_map(|v| match v {
-                    serde_yaml::Value::String(s) => Some(s.clone()),
-                    _ => None,
-                })
-                .collect::<Vec<_>>(),
-            serde_yaml::Value::String(s) => vec![s.clone()],
-            _ => Vec::new(),
-        };
-
-        let targets = targets
-            .into_iter()
-            .filter(|t| ix_core::entity::looks_like_entity_id(t))
-            .collect::<Vec<_>>();
-
-        if targets.is_empty() {
-            continue;
-        }
-
-        rels.push((key.clone(), targets));
-    }
-
-    rels.sort_by(|a, b| a.0.cmp(&b.0));
-    rels
 }
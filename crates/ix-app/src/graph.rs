@@ -0,0 +1,213 @@
+//! Relationship views (`graph`, `context`) shared by the CLI and the MCP server.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Result;
+use ix_core::markdown::get_string;
+use ix_core::repo::IxchelRepo;
+use serde_json::{Value, json};
+
+/// Qualifiers on a relationship target other than its `id`.
+pub type EdgeProperties = BTreeMap<String, String>;
+
+/// What a relationship target resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTitle {
+    /// The target can't be read.
+    Missing,
+    /// The target exists but has no `title`.
+    Untitled,
+    Titled(String),
+}
+
+impl LinkTitle {
+    #[must_use]
+    pub fn title(&self) -> Option<String> {
+        match self {
+            Self::Titled(title) => Some(title.clone()),
+            Self::Missing | Self::Untitled => None,
+        }
+    }
+
+    #[must_use]
+    pub const fn exists(&self) -> bool {
+        !matches!(self, Self::Missing)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphTarget {
+    pub id: String,
+    pub title: LinkTitle,
+    pub properties: EdgeProperties,
+}
+
+/// An entity and its outgoing relationships, sorted by relationship name.
+#[derive(Debug, Clone)]
+pub struct EntityGraph {
+    pub id: String,
+    pub title: String,
+    pub outgoing: Vec<(String, Vec<GraphTarget>)>,
+}
+
+/// A relationship from the context root to one item.
+#[derive(Debug, Clone)]
+pub struct ContextEdge {
+    pub rel: String,
+    pub properties: EdgeProperties,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContextItem {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    /// Empty for the root itself.
+    pub edges: Vec<ContextEdge>,
+}
+
+/// Outgoing relationships of `id` (already resolved), with each target's title.
+pub fn collect_graph(repo: &IxchelRepo, id: &str) -> Result<EntityGraph> {
+    let doc = repo.read_document(id)?;
+    let title = get_string(&doc.frontmatter, "title").unwrap_or_default();
+    let mut rels = repo.extract_relationship_targets(&doc.frontmatter);
+    rels.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let ids = rels
+        .iter()
+        .flat_map(|(_, targets)| targets.iter().map(|t| t.id.clone()))
+        .collect::<Vec<_>>();
+    let titles = link_titles(repo, &ids);
+
+    let outgoing = rels
+        .into_iter()
+        .map(|(rel, targets)| {
+            let targets = targets
+                .into_iter()
+                .map(|target| GraphTarget {
+                    title: titles
+                        .get(&target.id)
+                        .cloned()
+                        .unwrap_or(LinkTitle::Missing),
+                    id: target.id,
+                    properties: target.properties,
+                })
+                .collect();
+            (rel, targets)
+        })
+        .collect();
+
+    Ok(EntityGraph {
+        id: id.to_string(),
+        title,
+        outgoing,
+    })
+}
+
+/// Resolve the title of every id in `ids`, reading each entity once.
+#[must_use]
+pub fn link_titles(repo: &IxchelRepo, ids: &[String]) -> BTreeMap<String, LinkTitle> {
+    let ids = ids
+        .iter()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let docs = repo.read_documents(&ids);
+    ids.into_iter()
+        .zip(docs)
+        .map(|(id, doc)| {
+            let title = match doc {
+                Err(_) => LinkTitle::Missing,
+                Ok(doc) => get_string(&doc.frontmatter, "title")
+                    .map_or(LinkTitle::Untitled, LinkTitle::Titled),
+            };
+            (id, title)
+        })
+        .collect()
+}
+
+/// `id` (already resolved) plus every entity it links to, sorted by id.
+///
+/// Each item keeps one edge per (relationship, target), so a target linked under two
+/// keys keeps each edge's qualifiers.
+pub fn collect_context(repo: &IxchelRepo, id: &str) -> Result<Vec<ContextItem>> {
+    let doc = repo.read_document(id)?;
+
+    let mut edges = BTreeMap::<String, Vec<ContextEdge>>::new();
+    edges.insert(id.to_string(), Vec::new());
+    let mut rels = repo.extract_relationship_targets(&doc.frontmatter);
+    rels.sort_by(|a, b| a.0.cmp(&b.0));
+    for (rel, targets) in rels {
+        for target in targets {
+            edges.entry(target.id).or_default().push(ContextEdge {
+                rel: rel.clone(),
+                properties: target.properties,
+            });
+        }
+    }
+
+    let ids = edges.keys().cloned().collect::<Vec<_>>();
+    let docs = repo.read_documents(&ids);
+    edges
+        .into_iter()
+        .zip(docs)
+        .map(|((id, edges), doc)| {
+            let doc = doc?;
+            Ok(ContextItem {
+                id,
+                title: get_string(&doc.frontmatter, "title").unwrap_or_default(),
+                body: doc.body,
+                edges,
+            })
+        })
+        .collect()
+}
+
+/// `{ id, title, outgoing: [{ rel, targets: [{ id, title, properties? }] }] }`.
+pub fn graph_json(repo: &IxchelRepo, id: &str) -> Result<Value> {
+    let graph = collect_graph(repo, id)?;
+    let outgoing = graph
+        .outgoing
+        .into_iter()
+        .map(|(rel, targets)| {
+            let targets = targets
+                .into_iter()
+                .map(|target| {
+                    let mut out = json!({ "id": target.id, "title": target.title.title() });
+                    if !target.properties.is_empty() {
+                        out["properties"] = json!(target.properties);
+                    }
+                    out
+                })
+                .collect::<Vec<_>>();
+            json!({ "rel": rel, "targets": targets })
+        })
+        .collect::<Vec<_>>();
+    Ok(json!({ "id": graph.id, "title": graph.title, "outgoing": outgoing }))
+}
+
+/// `{ id, items: [{ id, title, body, edges?: [{ rel, properties? }] }] }`.
+pub fn context_json(repo: &IxchelRepo, id: &str) -> Result<Value> {
+    let items = collect_context(repo, id)?
+        .into_iter()
+        .map(|item| {
+            let mut out = json!({ "id": item.id, "title": item.title, "body": item.body });
+            if !item.edges.is_empty() {
+                out["edges"] = item
+                    .edges
+                    .into_iter()
+                    .map(|edge| {
+                        let mut out = json!({ "rel": edge.rel });
+                        if !edge.properties.is_empty() {
+                            out["properties"] = json!(edge.properties);
+                        }
+                        out
+                    })
+                    .collect();
+            }
+            out
+        })
+        .collect::<Vec<_>>();
+    Ok(json!({ "id": id, "items": items }))
+}
@@ -1,7 +1,8 @@
 //! Ixchel application wiring layer.
 //!
 //! This crate provides the high-level API for interacting with Ixchel storage backends.
-//! Currently only `SurrealDB` is supported in the published crate.
+//! Currently only `SurrealDB` is supported in the published crate, plus an
+//! in-memory backend (`storage.backend = "memory"`) for tests and small repos.
 //!
 //! # `HelixDB` Support
 //!
@@ -9,20 +10,34 @@
 //! dependency. If you need `HelixDB` support, build from source:
 //! <https://github.com/kevinmichaelchen/ixchel>
 
+pub mod graph;
+mod memory;
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
 use anyhow::{Context, Result};
-use ix_core::index::{IndexBackend, SearchHit, SyncStats};
-use ix_core::repo::IxchelRepo;
+use chrono::Utc;
+use ix_core::index::{INDEX_SCHEMA_VERSION, IndexBackend, IndexManifest, SearchHit, SyncStats};
+use ix_core::repo::{HealthCheck, IxchelRepo, ListSort};
+
+pub use memory::InMemoryIndex;
 
 fn backend_name(repo: &IxchelRepo) -> String {
     repo.config.storage.backend.trim().to_ascii_lowercase()
 }
 
-pub fn sync(repo: &IxchelRepo) -> Result<SyncStats> {
+/// Construct the index backend selected by `storage.backend` in the repo config.
+pub fn open_index(repo: &IxchelRepo) -> Result<Box<dyn IndexBackend>> {
     match backend_name(repo).as_str() {
         "surrealdb" => {
-            let mut index =
+            let index =
                 ix_storage_surrealdb::SurrealDbIndex::open(repo).context("open surrealdb index")?;
-            IndexBackend::sync(&mut index, repo).context("sync surrealdb index")
+            Ok(Box::new(index))
+        }
+        "memory" => {
+            let index = InMemoryIndex::open(repo).context("open memory index")?;
+            Ok(Box::new(index))
         }
         "helixdb" => anyhow::bail!(
             "HelixDB backend is not available in this build. \
@@ -34,36 +49,221 @@ pub fn sync(repo: &IxchelRepo) -> Result<SyncStats> {
     }
 }
 
+pub fn sync(repo: &IxchelRepo) -> Result<SyncStats> {
+    sync_with_progress(repo, &mut |_| {})
+}
+
+/// Sync the index, reporting running totals through `progress`.
+pub fn sync_with_progress(
+    repo: &IxchelRepo,
+    progress: &mut dyn FnMut(&SyncStats),
+) -> Result<SyncStats> {
+    let backend = backend_name(repo);
+    let mut index = open_index(repo)?;
+    let stats = index
+        .sync_with_progress(repo, progress)
+        .with_context(|| format!("sync {backend} index"))?;
+
+    write_index_manifest(repo, &backend, stats)?;
+    Ok(stats)
+}
+
+/// Entity counts and on-disk size before and after [`gc`].
+#[derive(Debug, Clone, Copy)]
+pub struct GcReport {
+    /// Entity count from the previous manifest, if the index had been synced.
+    pub entities_before: Option<u32>,
+    pub entities_after: u32,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
+/// Rebuild the index from scratch, reusing embeddings by content hash unless `reembed`.
+pub fn gc(repo: &IxchelRepo, reembed: bool) -> Result<GcReport> {
+    let backend = backend_name(repo);
+    let storage_path = repo.paths.ixchel_dir().join(&repo.config.storage.path);
+    let entities_before = index_status(repo)?.map(|manifest| manifest.entity_count);
+    let bytes_before = dir_size(&storage_path)?;
+
+    let mut index = open_index(repo)?;
+    let stats = index
+        .rebuild(repo, reembed)
+        .with_context(|| format!("rebuild {backend} index"))?;
+    drop(index);
+    write_index_manifest(repo, &backend, stats)?;
+
+    Ok(GcReport {
+        entities_before,
+        entities_after: stats.added + stats.modified + stats.unchanged,
+        bytes_before,
+        bytes_after: dir_size(&storage_path)?,
+    })
+}
+
+fn dir_size(path: &Path) -> Result<u64> {
+    if path.is_file() {
+        return Ok(path.metadata()?.len());
+    }
+    if !path.is_dir() {
+        return Ok(0);
+    }
+
+    let mut total = 0;
+    for entry in
+        std::fs::read_dir(path).with_context(|| format!("Failed to read {}", path.display()))?
+    {
+        total += dir_size(&entry?.path())?;
+    }
+    Ok(total)
+}
+
+/// Read the manifest written by the last successful sync, if any.
+pub fn index_status(repo: &IxchelRepo) -> Result<Option<IndexManifest>> {
+    IndexManifest::load(&repo.paths)
+}
+
+/// Warnings about configuration drift since the index was last synced.
+pub fn index_warnings(repo: &IxchelRepo) -> Result<Vec<String>> {
+    Ok(index_status(repo)?
+        .map(|manifest| manifest.warnings(&backend_name(repo), &repo.config))
+        .unwrap_or_default())
+}
+
+fn write_index_manifest(repo: &IxchelRepo, backend: &str, stats: SyncStats) -> Result<()> {
+    let mut kind_counts: BTreeMap<String, u32> = BTreeMap::new();
+    for item in repo.list_with_options(None, ListSort::default(), true)? {
+        *kind_counts
+            .entry(item.kind.as_str().to_string())
+            .or_default() += 1;
+    }
+
+    let embeds = backend != "memory";
+    let manifest = IndexManifest {
+        schema_version: INDEX_SCHEMA_VERSION,
+        backend: backend.to_string(),
+        embedding_provider: embeds.then(|| repo.config.embedding.provider.clone()),
+        embedding_model: embeds.then(|| repo.config.embedding.model.clone()),
+        embedding_dimension: repo.config.embedding.dimension.filter(|_| embeds),
+        entity_count: stats.added + stats.modified + stats.unchanged,
+        kind_counts,
+        last_sync: Utc::now(),
+    };
+    manifest.save(&repo.paths).context("write index manifest")
+}
+
 pub fn search(repo: &IxchelRepo, query: &str, limit: usize) -> Result<Vec<SearchHit>> {
-    match backend_name(repo).as_str() {
-        "surrealdb" => {
-            let index =
-                ix_storage_surrealdb::SurrealDbIndex::open(repo).context("open surrealdb index")?;
-            IndexBackend::search(&index, query, limit).context("search surrealdb index")
-        }
-        "helixdb" => anyhow::bail!(
-            "HelixDB backend is not available in this build. \
-             The crates.io version only supports SurrealDB because helix-db \
-             uses a git dependency. Build from source for HelixDB support: \
-             https://github.com/kevinmichaelchen/ixchel"
-        ),
-        backend => anyhow::bail!("Unsupported storage backend: {backend}"),
+    search_with_options(repo, query, limit, false)
+}
+
+/// Search the index, dropping archived entities unless `include_archived` is set.
+pub fn search_with_options(
+    repo: &IxchelRepo,
+    query: &str,
+    limit: usize,
+    include_archived: bool,
+) -> Result<Vec<SearchHit>> {
+    if let Some(manifest) = index_status(repo)? {
+        manifest.ensure_supported()?;
+    }
+
+    let index = open_index(repo)?;
+    if include_archived {
+        return index
+            .search(query, limit)
+            .with_context(|| format!("search {} index", backend_name(repo)));
     }
+
+    let archived = repo
+        .list_archived(None, ListSort::default())?
+        .into_iter()
+        .map(|item| item.id)
+        .collect::<HashSet<_>>();
+    let mut hits = index
+        .search(query, limit.saturating_add(archived.len()))
+        .with_context(|| format!("search {} index", backend_name(repo)))?;
+    hits.retain(|hit| !archived.contains(&hit.id));
+    hits.truncate(limit);
+    Ok(hits)
 }
 
 pub fn health_check(repo: &IxchelRepo) -> Result<()> {
-    match backend_name(repo).as_str() {
-        "surrealdb" => {
-            let index =
-                ix_storage_surrealdb::SurrealDbIndex::open(repo).context("open surrealdb index")?;
-            IndexBackend::health_check(&index).context("surrealdb health check")
+    let index = open_index(repo)?;
+    index
+        .health_check()
+        .with_context(|| format!("{} health check", backend_name(repo)))
+}
+
+/// Index-level health checks for `ixchel doctor`: manifest compatibility, embedder, and
+/// whether the index can be opened and queried.
+#[must_use]
+pub fn index_health(repo: &IxchelRepo) -> Vec<HealthCheck> {
+    let backend = backend_name(repo);
+    let mut checks = Vec::new();
+
+    let manifest = match index_status(repo) {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            checks.push(HealthCheck::fail(
+                "index schema",
+                format!("{err:#}"),
+                "Run `ixchel gc` to rebuild the index.",
+            ));
+            return checks;
         }
-        "helixdb" => anyhow::bail!(
-            "HelixDB backend is not available in this build. \
-             The crates.io version only supports SurrealDB because helix-db \
-             uses a git dependency. Build from source for HelixDB support: \
-             https://github.com/kevinmichaelchen/ixchel"
-        ),
-        backend => anyhow::bail!("Unsupported storage backend: {backend}"),
+    };
+    match &manifest {
+        None => checks.push(HealthCheck::warn(
+            "index schema",
+            "index has not been synced yet",
+            "Run `ixchel sync`.",
+        )),
+        Some(manifest) => match manifest.ensure_supported() {
+            Ok(()) => {
+                checks.push(HealthCheck::pass(
+                    "index schema",
+                    format!("version {}", manifest.schema_version),
+                ));
+                for warning in manifest.warnings(&backend, &repo.config) {
+                    checks.push(HealthCheck::warn(
+                        "index config",
+                        warning,
+                        "Run `ixchel sync`.",
+                    ));
+                }
+            }
+            Err(err) => checks.push(HealthCheck::fail(
+                "index schema",
+                format!("{err:#}"),
+                "Upgrade ixchel or run `ixchel gc`.",
+            )),
+        },
+    }
+
+    if backend != "memory" {
+        let embedding = &repo.config.embedding;
+        checks.push(match ix_embeddings::Embedder::with_config(embedding) {
+            Ok(embedder) => HealthCheck::pass(
+                "embedder",
+                format!("{} ({} dims)", embedding.model, embedder.dimension()),
+            ),
+            Err(err) => HealthCheck::fail(
+                "embedder",
+                err.to_string(),
+                "Check `[embedding]` provider and model in the config.",
+            ),
+        });
+    }
+
+    if manifest.is_some() {
+        checks.push(match health_check(repo) {
+            Ok(()) => HealthCheck::pass("index", format!("{backend} index opened and queried")),
+            Err(err) => HealthCheck::fail(
+                "index",
+                format!("{err:#}"),
+                "Run `ixchel gc` to rebuild the index.",
+            ),
+        });
     }
+
+    checks
 }
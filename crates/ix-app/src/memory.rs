@@ -0,0 +1,159 @@
+//! In-memory index backend.
+//!
+//! Nothing is persisted: the index is rebuilt from the markdown files every time it
+//! is opened. Intended for tests and very small repositories where running a real
+//! database is not worth it.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result};
+use ix_core::entity::{EntityKind, kind_from_id};
+use ix_core::index::{IndexBackend, SearchHit, SyncStats};
+use ix_core::markdown::{get_string, get_string_list, parse_markdown};
+use ix_core::repo::{IxchelRepo, ListSort};
+
+#[derive(Debug, Clone)]
+struct MemoryEntry {
+    kind: Option<EntityKind>,
+    title: String,
+    raw: String,
+    haystack: String,
+}
+
+/// Index backend that keeps every entity in memory and matches query terms lexically.
+#[derive(Debug, Default)]
+pub struct InMemoryIndex {
+    entries: BTreeMap<String, MemoryEntry>,
+}
+
+impl InMemoryIndex {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an index populated from the repository's current markdown files.
+    pub fn open(repo: &IxchelRepo) -> Result<Self> {
+        let mut index = Self::new();
+        index.sync(repo)?;
+        Ok(index)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl IndexBackend for InMemoryIndex {
+    fn sync(&mut self, repo: &IxchelRepo) -> Result<SyncStats> {
+        self.sync_with_progress(repo, &mut |_| {})
+    }
+
+    fn sync_with_progress(
+        &mut self,
+        repo: &IxchelRepo,
+        progress: &mut dyn FnMut(&SyncStats),
+    ) -> Result<SyncStats> {
+        let mut stats = SyncStats::default();
+        let mut seen = BTreeSet::new();
+
+        for item in repo.list_with_options(None, ListSort::default(), true)? {
+            stats.scanned += 1;
+            progress(&stats);
+
+            let raw = std::fs::read_to_string(&item.path)
+                .with_context(|| format!("Failed to read {}", item.path.display()))?;
+            if item.id.trim().is_empty() {
+                continue;
+            }
+            seen.insert(item.id.clone());
+
+            if self
+                .entries
+                .get(&item.id)
+                .is_some_and(|entry| entry.raw == raw)
+            {
+                stats.unchanged += 1;
+                continue;
+            }
+
+            let doc = parse_markdown(&item.path, &raw)?;
+            let kind = get_string(&doc.frontmatter, "type")
+                .and_then(|t| t.parse::<EntityKind>().ok())
+                .or_else(|| kind_from_id(&item.id));
+            let tags = get_string_list(&doc.frontmatter, "tags");
+            let haystack =
+                format!("{}\n{}\n{}", item.title, doc.body, tags.join(" ")).to_lowercase();
+
+            let entry = MemoryEntry {
+                kind,
+                title: item.title,
+                raw,
+                haystack,
+            };
+            if self.entries.insert(item.id, entry).is_some() {
+                stats.modified += 1;
+            } else {
+                stats.added += 1;
+            }
+        }
+
+        let before = self.entries.len();
+        self.entries.retain(|id, _| seen.contains(id));
+        stats.deleted = u32::try_from(before - self.entries.len()).unwrap_or(u32::MAX);
+
+        Ok(stats)
+    }
+
+    fn rebuild(&mut self, repo: &IxchelRepo, _reembed: bool) -> Result<SyncStats> {
+        self.entries.clear();
+        self.sync(repo)
+    }
+
+    fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        let terms = query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut hits = self
+            .entries
+            .iter()
+            .filter_map(|(id, entry)| {
+                let matched = terms
+                    .iter()
+                    .filter(|term| entry.haystack.contains(term.as_str()))
+                    .count();
+                if matched == 0 {
+                    return None;
+                }
+
+                #[allow(clippy::cast_precision_loss)]
+                let score = matched as f32 / terms.len() as f32;
+                Some(SearchHit {
+                    score,
+                    id: id.clone(),
+                    kind: entry.kind,
+                    title: entry.title.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        hits.truncate(limit);
+        Ok(hits)
+    }
+
+    fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
@@ -56,7 +57,8 @@ pub fn ixchel_log_dir() -> PathBuf {
 
 /// Shared configuration used by multiple Ixchel tools.
 ///
-/// Loaded from `~/.ixchel/config/config.toml` and `.ixchel/config.toml`.
+/// Loaded from `~/.ixchel/config/config.toml` and `.ixchel/config.toml`, unless a
+/// single file is forced via [`ConfigLoader::with_config_file`].
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct IxchelConfig {
     #[serde(default)]
@@ -65,6 +67,11 @@ pub struct IxchelConfig {
     pub embedding: EmbeddingConfig,
     #[serde(default)]
     pub storage: StorageConfig,
+    #[serde(default, skip_serializing_if = "RelationshipsConfig::is_empty")]
+    pub relationships: RelationshipsConfig,
+    /// Per-kind validation rules, keyed by entity kind (e.g. `[schema.decision]`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub schema: BTreeMap<String, KindSchemaConfig>,
 }
 
 pub type SharedConfig = IxchelConfig;
@@ -119,7 +126,7 @@ const fn default_batch_size() -> usize {
 /// Storage configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct StorageConfig {
-    /// Storage backend to use (e.g. "helixdb", "surrealdb").
+    /// Storage backend to use (e.g. "helixdb", "surrealdb", "memory").
     #[serde(default = "default_storage_backend")]
     pub backend: String,
 
@@ -152,12 +159,56 @@ fn default_storage_path() -> String {
     "data/ixchel".to_string()
 }
 
+/// Frontmatter validation rules for one entity kind.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct KindSchemaConfig {
+    /// Allowed `status` values. When unset, any status is accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<Vec<String>>,
+
+    /// `chrono` format string for the `date` key. Defaults to `%Y-%m-%d`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_format: Option<String>,
+
+    /// Frontmatter keys that must be present and non-empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+}
+
+/// Controls which frontmatter keys are treated as relationships.
+///
+/// By default every non-metadata key whose values look like entity ids is a relationship.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RelationshipsConfig {
+    /// Additional frontmatter keys to treat as plain metadata (e.g. "owner").
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metadata_keys: Vec<String>,
+
+    /// When set, only these frontmatter keys are treated as relationships.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<Vec<String>>,
+}
+
+impl RelationshipsConfig {
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.metadata_keys.is_empty() && self.allowed.is_none()
+    }
+}
+
 impl IxchelConfig {
+    /// Serializes the config as pretty-printed TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
     pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
-        let raw = toml::to_string_pretty(self).map_err(|source| ConfigError::SerializeError {
-            path: path.to_path_buf(),
-            source,
-        })?;
+        let raw = self
+            .to_toml()
+            .map_err(|source| ConfigError::SerializeError {
+                path: path.to_path_buf(),
+                source,
+            })?;
         std::fs::write(path, raw).map_err(|source| ConfigError::WriteError {
             path: path.to_path_buf(),
             source,
@@ -214,6 +265,7 @@ pub struct ConfigLoader {
     env_prefix: Option<String>,
     project_dir: Option<PathBuf>,
     global_dir: Option<PathBuf>,
+    config_file: Option<PathBuf>,
 }
 
 impl ConfigLoader {
@@ -223,6 +275,7 @@ impl ConfigLoader {
             env_prefix: None,
             project_dir: None,
             global_dir: None,
+            config_file: None,
         }
     }
 
@@ -244,7 +297,24 @@ impl ConfigLoader {
         self
     }
 
+    /// Load exactly this file instead of discovering project and global config.
+    #[must_use]
+    pub fn with_config_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_file = Some(path.into());
+        self
+    }
+
     pub fn load<T: DeserializeOwned + Default>(self) -> Result<T, ConfigError> {
+        if let Some(path) = self.config_file {
+            let content =
+                std::fs::read_to_string(&path).map_err(|source| ConfigError::ReadError {
+                    path: path.clone(),
+                    source,
+                })?;
+            return toml::from_str(&content)
+                .map_err(|source| ConfigError::ParseError { path, source });
+        }
+
         let mut merged = toml::Table::new();
 
         let global_dir = self.global_dir.unwrap_or_else(ixchel_config_dir);
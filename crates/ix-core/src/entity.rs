@@ -15,6 +15,18 @@ pub enum EntityKind {
 }
 
 impl EntityKind {
+    /// Every kind, in canonical order.
+    pub const ALL: [Self; 8] = [
+        Self::Decision,
+        Self::Issue,
+        Self::Idea,
+        Self::Report,
+        Self::Source,
+        Self::Citation,
+        Self::Agent,
+        Self::Session,
+    ];
+
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
@@ -1,8 +1,17 @@
-use anyhow::Result;
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use ix_config::IxchelConfig;
+use serde::{Deserialize, Serialize};
 
 use crate::entity::EntityKind;
+use crate::paths::IxchelPaths;
 use crate::repo::IxchelRepo;
 
+/// Version of the on-disk index layout written by this binary.
+pub const INDEX_SCHEMA_VERSION: u32 = 1;
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct SyncStats {
     pub scanned: u32,
@@ -20,8 +29,153 @@ pub struct SearchHit {
     pub title: String,
 }
 
+/// Min-max normalize hit scores into `0.0..=1.0`, in hit order.
+///
+/// Scores are only comparable within one result set, so the best hit maps to `1.0`
+/// and the worst to `0.0`. A single hit, or a set where every score ties, maps to `1.0`.
+#[must_use]
+pub fn normalize_scores(hits: &[SearchHit]) -> Vec<f32> {
+    let (min, max) = hits
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), h| {
+            (lo.min(h.score), hi.max(h.score))
+        });
+    let range = max - min;
+    hits.iter()
+        .map(|h| {
+            if range.is_finite() && range > f32::EPSILON {
+                ((h.score - min) / range).clamp(0.0, 1.0)
+            } else {
+                1.0
+            }
+        })
+        .collect()
+}
+
 pub trait IndexBackend: Send + Sync {
     fn sync(&mut self, repo: &IxchelRepo) -> Result<SyncStats>;
+
+    /// Like [`Self::sync`], calling `progress` with running totals as entities are scanned.
+    ///
+    /// The default reports once, after the sync completes.
+    fn sync_with_progress(
+        &mut self,
+        repo: &IxchelRepo,
+        progress: &mut dyn FnMut(&SyncStats),
+    ) -> Result<SyncStats> {
+        let stats = self.sync(repo)?;
+        progress(&stats);
+        Ok(stats)
+    }
+
+    /// Discard all index state and re-sync from the entity files.
+    ///
+    /// Unless `reembed` is set, backends may reuse embeddings for unchanged content. The
+    /// default suits backends whose `sync` already rebuilds from scratch.
+    fn rebuild(&mut self, repo: &IxchelRepo, reembed: bool) -> Result<SyncStats> {
+        let _ = reembed;
+        self.sync(repo)
+    }
+
     fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchHit>>;
     fn health_check(&self) -> Result<()>;
 }
+
+/// Metadata written next to the index after every sync.
+///
+/// Unknown fields are ignored so older binaries can still read newer manifests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexManifest {
+    pub schema_version: u32,
+    pub backend: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_provider: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_dimension: Option<usize>,
+    pub entity_count: u32,
+    #[serde(default)]
+    pub kind_counts: BTreeMap<String, u32>,
+    pub last_sync: DateTime<Utc>,
+}
+
+impl IndexManifest {
+    pub fn load(paths: &IxchelPaths) -> Result<Option<Self>> {
+        let path = paths.index_manifest_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let raw = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let manifest = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(manifest))
+    }
+
+    pub fn save(&self, paths: &IxchelPaths) -> Result<()> {
+        let path = paths.index_manifest_path();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let raw = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, raw).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Fail if the index was written by a newer, incompatible binary.
+    pub fn ensure_supported(&self) -> Result<()> {
+        if self.schema_version > INDEX_SCHEMA_VERSION {
+            anyhow::bail!(
+                "Index schema version {} is newer than this binary supports ({INDEX_SCHEMA_VERSION}). Upgrade ixchel or delete .ixchel/data/ and run `ixchel sync`.",
+                self.schema_version
+            );
+        }
+        Ok(())
+    }
+
+    /// Whether the stored vectors came from the configured embedding provider and model.
+    ///
+    /// Manifests that predate `embedding_provider` never match.
+    #[must_use]
+    pub fn embeddings_match(&self, config: &IxchelConfig) -> bool {
+        self.embedding_provider.as_deref() == Some(config.embedding.provider.as_str())
+            && self.embedding_model.as_deref() == Some(config.embedding.model.as_str())
+    }
+
+    /// Describe configuration drift since the index was last synced.
+    #[must_use]
+    pub fn warnings(&self, backend: &str, config: &IxchelConfig) -> Vec<String> {
+        let mut out = Vec::new();
+
+        if self.backend != backend {
+            out.push(format!(
+                "index was built with the {} backend but {backend} is configured; run `ixchel sync`",
+                self.backend
+            ));
+        }
+
+        if let Some(model) = &self.embedding_model
+            && model != &config.embedding.model
+        {
+            out.push(format!(
+                "index was embedded with {model} but {} is configured; run `ixchel sync` to re-embed",
+                config.embedding.model
+            ));
+        }
+
+        if self.embedding_dimension.is_some()
+            && config.embedding.dimension.is_some()
+            && self.embedding_dimension != config.embedding.dimension
+        {
+            out.push(format!(
+                "index embedding dimension {:?} does not match configured dimension {:?}",
+                self.embedding_dimension, config.embedding.dimension
+            ));
+        }
+
+        out
+    }
+}
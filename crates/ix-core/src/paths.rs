@@ -37,6 +37,11 @@ impl IxchelPaths {
         self.ixchel_dir().join("data")
     }
 
+    #[must_use]
+    pub fn index_manifest_path(&self) -> PathBuf {
+        self.data_dir().join("index-manifest.json")
+    }
+
     #[must_use]
     pub fn kind_dir(&self, kind: EntityKind) -> PathBuf {
         self.ixchel_dir().join(kind.directory_name())
@@ -48,20 +53,27 @@ impl IxchelPaths {
         Some(self.kind_dir(kind).join(format!("{id}.md")))
     }
 
+    #[must_use]
+    pub fn archive_dir(&self) -> PathBuf {
+        self.ixchel_dir().join("archive")
+    }
+
+    #[must_use]
+    pub fn archive_kind_dir(&self, kind: EntityKind) -> PathBuf {
+        self.archive_dir().join(kind.directory_name())
+    }
+
+    #[must_use]
+    pub fn archived_entity_path(&self, id: &str) -> Option<PathBuf> {
+        let kind = kind_from_id(id)?;
+        Some(self.archive_kind_dir(kind).join(format!("{id}.md")))
+    }
+
     pub fn ensure_layout(&self) -> Result<()> {
         std::fs::create_dir_all(self.data_dir())
             .with_context(|| format!("Failed to create {}", self.data_dir().display()))?;
 
-        for kind in [
-            EntityKind::Decision,
-            EntityKind::Issue,
-            EntityKind::Idea,
-            EntityKind::Report,
-            EntityKind::Source,
-            EntityKind::Citation,
-            EntityKind::Agent,
-            EntityKind::Session,
-        ] {
+        for kind in EntityKind::ALL {
             let dir = self.kind_dir(kind);
             std::fs::create_dir_all(&dir)
                 .with_context(|| format!("Failed to create {}", dir.display()))?;
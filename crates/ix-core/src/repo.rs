@@ -1,11 +1,15 @@
 use std::cmp::Ordering;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Mutex, PoisonError};
 
 use anyhow::{Context, Result};
-use chrono::{DateTime, SecondsFormat, Utc};
-use ix_config::{ConfigLoader, IxchelConfig};
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use ix_config::{ConfigLoader, IxchelConfig, KindSchemaConfig};
 use serde_yaml::{Mapping, Value};
 use thiserror::Error;
 
@@ -63,9 +67,19 @@ pub struct CheckError {
     pub message: String,
 }
 
+/// What a [`CheckIssue`] is about, for callers that act on one class of problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckIssueKind {
+    /// A relationship points at an entity that exists neither active nor archived.
+    BrokenLink,
+    /// Any other validation problem; see the message.
+    Other,
+}
+
 #[derive(Debug)]
 pub struct CheckIssue {
     pub path: PathBuf,
+    pub kind: CheckIssueKind,
     pub message: String,
     pub suggestion: Option<String>,
 }
@@ -75,13 +89,162 @@ pub struct CheckReportDetailed {
     pub errors: Vec<CheckIssue>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    Pass,
+    /// Worth fixing, but not a failure.
+    Warn,
+    Fail,
+}
+
+impl HealthStatus {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+/// Result of one `ixchel doctor` check.
+#[derive(Debug)]
+pub struct HealthCheck {
+    pub name: String,
+    pub status: HealthStatus,
+    pub detail: String,
+    /// How to fix a warning or failure.
+    pub hint: Option<String>,
+}
+
+impl HealthCheck {
+    #[must_use]
+    pub fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status: HealthStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    #[must_use]
+    pub fn warn(
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status: HealthStatus::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    #[must_use]
+    pub fn fail(
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            status: HealthStatus::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Schema-conformance result for one entity, from [`IxchelRepo::validate_schema`].
+#[derive(Debug)]
+pub struct SchemaCheck {
+    pub id: String,
+    pub kind: EntityKind,
+    pub path: PathBuf,
+    /// `false` when the kind has no `[schema.<kind>]` table, so nothing was checked.
+    pub has_schema: bool,
+    pub violations: Vec<SchemaViolation>,
+}
+
+#[derive(Debug)]
+pub struct SchemaViolation {
+    pub field: String,
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+/// Planned or applied result of [`IxchelRepo::move_entity`].
+#[derive(Debug)]
+pub struct MoveReport {
+    pub old_id: String,
+    pub new_id: String,
+    pub kind: EntityKind,
+    pub old_path: PathBuf,
+    pub new_path: PathBuf,
+    /// Entities whose relationships were rewritten to the new id.
+    pub rewritten: Vec<String>,
+}
+
+/// One relationship edge for [`IxchelRepo::link_many`].
+#[derive(Debug, Clone)]
+pub struct LinkRequest {
+    pub from: String,
+    pub rel: String,
+    pub to: String,
+}
+
+#[derive(Debug, Default)]
+pub struct LinkBatchReport {
+    pub added: usize,
+    /// Links that were already present (including repeats within the batch).
+    pub skipped: usize,
+    pub errors: Vec<LinkBatchError>,
+}
+
+#[derive(Debug)]
+pub struct LinkBatchError {
+    /// Position of the failing request in the input slice.
+    pub index: usize,
+    pub message: String,
+    /// The target entity does not exist (see [`LinkError::MissingTarget`]).
+    pub missing_target: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum LinkError {
+    /// Linking to an entity that doesn't exist yet requires `allow_dangling`.
+    #[error("Target does not exist: {id} ({})", path.display())]
+    MissingTarget { id: String, path: PathBuf },
+}
+
+/// A relationship target, either a bare id or a mapping like `{ id: dec-abc, since: ... }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationTarget {
+    pub id: String,
+    /// Qualifiers other than `id`, stringified. Empty for bare-id targets.
+    pub properties: BTreeMap<String, String>,
+}
+
+/// Lowercased alias -> sorted entity ids, plus a stamp of the files it was built from.
+#[derive(Debug)]
+struct AliasIndex {
+    stamp: u64,
+    ids: HashMap<String, Vec<String>>,
+}
+
 #[derive(Debug)]
 pub struct IxchelRepo {
     pub paths: IxchelPaths,
     pub config: IxchelConfig,
+    /// Alias index built on the first alias lookup, rebuilt when entity files change.
+    aliases: Mutex<Option<AliasIndex>>,
 }
 
-const METADATA_KEYS: &[&str] = &[
+/// Frontmatter keys that are never treated as relationships.
+pub const METADATA_KEYS: &[&str] = &[
     "id",
     "type",
     "title",
@@ -91,12 +254,27 @@ const METADATA_KEYS: &[&str] = &[
     "updated_at",
     "created_by",
     "tags",
+    "aliases",
 ];
 
+/// Environment variable naming a config file that replaces discovered repo config.
+pub const CONFIG_ENV_VAR: &str = "IXCHEL_CONFIG";
+
+const MAX_READ_THREADS: usize = 8;
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
 const KNOWN_ID_PREFIXES_HINT: &str = "dec, iss, bd, idea, rpt, src, cite, agt, ses";
 
 impl IxchelRepo {
     pub fn open_from(start: &Path) -> Result<Self> {
+        Self::open_from_with_config(start, None)
+    }
+
+    /// Open the repo containing `start`, loading `config_path` instead of discovered config.
+    ///
+    /// Precedence is `config_path` > `IXCHEL_CONFIG` > project/global config > defaults.
+    pub fn open_from_with_config(start: &Path, config_path: Option<&Path>) -> Result<Self> {
         let repo_root = find_git_root(start).with_context(|| {
             format!(
                 "Not inside a git repository (no .git found above {})",
@@ -113,12 +291,21 @@ impl IxchelRepo {
             );
         }
 
-        let config: IxchelConfig = ConfigLoader::new("").with_project_dir(ixchel_dir).load()?;
+        let config = load_repo_config(ixchel_dir, config_path)?;
 
-        Ok(Self { paths, config })
+        Ok(Self {
+            paths,
+            config,
+            aliases: Mutex::default(),
+        })
     }
 
     pub fn init_from(start: &Path, force: bool) -> Result<Self> {
+        Self::init_from_with_options(start, force, false)
+    }
+
+    /// Like [`Self::init_from`], optionally scaffolding a sample config and decision.
+    pub fn init_from_with_options(start: &Path, force: bool, with_examples: bool) -> Result<Self> {
         let repo_root = find_git_root(start).with_context(|| {
             format!(
                 "Not inside a git repository (no .git found above {})",
@@ -126,10 +313,18 @@ impl IxchelRepo {
             )
         })?;
 
-        Self::init_at(&repo_root, force)
+        Self::init_at_with_options(&repo_root, force, with_examples)
     }
 
     pub fn init_at(repo_root: &Path, force: bool) -> Result<Self> {
+        Self::init_at_with_options(repo_root, force, false)
+    }
+
+    pub fn init_at_with_options(
+        repo_root: &Path,
+        force: bool,
+        with_examples: bool,
+    ) -> Result<Self> {
         let paths = IxchelPaths::new(repo_root.to_path_buf());
         let ixchel_dir = paths.ixchel_dir();
 
@@ -147,11 +342,24 @@ impl IxchelRepo {
 
         let config_path = paths.config_path();
         if force || !config_path.exists() {
-            IxchelConfig::default().save(&config_path)?;
+            if with_examples {
+                std::fs::write(&config_path, example_config()?)
+                    .with_context(|| format!("Failed to write {}", config_path.display()))?;
+            } else {
+                IxchelConfig::default().save(&config_path)?;
+            }
+        }
+
+        if with_examples {
+            write_example_decision(&paths, force)?;
         }
 
-        let config: IxchelConfig = ConfigLoader::new("").with_project_dir(ixchel_dir).load()?;
-        Ok(Self { paths, config })
+        let config = load_repo_config(ixchel_dir, None)?;
+        Ok(Self {
+            paths,
+            config,
+            aliases: Mutex::default(),
+        })
     }
 
     pub fn create_entity(
@@ -211,8 +419,7 @@ impl IxchelRepo {
         let doc = MarkdownDocument { frontmatter, body };
         let markdown = render_markdown(&doc)?;
 
-        std::fs::write(&path, markdown)
-            .with_context(|| format!("Failed to write {}", path.display()))?;
+        write_entity_atomic(&path, &markdown)?;
 
         Ok(EntitySummary {
             id,
@@ -222,7 +429,157 @@ impl IxchelRepo {
         })
     }
 
+    /// Resolve an entity id or one of its `aliases` to the canonical id.
+    pub fn resolve_id(&self, id_or_alias: &str) -> Result<String> {
+        let trimmed = id_or_alias.trim();
+        if self
+            .paths
+            .entity_path(trimmed)
+            .is_some_and(|path| path.exists())
+        {
+            return Ok(trimmed.to_string());
+        }
+
+        let candidates = self.alias_matches(trimmed)?;
+        match candidates.as_slice() {
+            [] if kind_from_id(trimmed).is_some() => Ok(trimmed.to_string()),
+            [] => anyhow::bail!("Unknown entity id or alias: {trimmed}"),
+            [id] => Ok(id.clone()),
+            _ => anyhow::bail!(
+                "Ambiguous alias {trimmed}: matches {}",
+                candidates.join(", ")
+            ),
+        }
+    }
+
+    /// Entity ids declaring `alias`, rescanning the repo only when entity files changed.
+    fn alias_matches(&self, alias: &str) -> Result<Vec<String>> {
+        let stamp = self.entity_files_stamp()?;
+        let mut aliases = self.aliases.lock().unwrap_or_else(PoisonError::into_inner);
+        if aliases.as_ref().is_none_or(|index| index.stamp != stamp) {
+            *aliases = Some(AliasIndex {
+                stamp,
+                ids: self.build_alias_index()?,
+            });
+        }
+        let matches = aliases
+            .as_ref()
+            .and_then(|index| index.ids.get(&alias.to_ascii_lowercase()))
+            .cloned()
+            .unwrap_or_default();
+        drop(aliases);
+        Ok(matches)
+    }
+
+    fn build_alias_index(&self) -> Result<HashMap<String, Vec<String>>> {
+        let mut index: HashMap<String, Vec<String>> = HashMap::new();
+        for item in self.list(None, ListSort::default())? {
+            let raw = std::fs::read_to_string(&item.path)
+                .with_context(|| format!("Failed to read {}", item.path.display()))?;
+            let doc = parse_markdown(&item.path, &raw)?;
+            for alias in get_string_list(&doc.frontmatter, "aliases") {
+                let ids = index.entry(alias.trim().to_ascii_lowercase()).or_default();
+                if !ids.contains(&item.id) {
+                    ids.push(item.id.clone());
+                }
+            }
+        }
+        for ids in index.values_mut() {
+            ids.sort();
+        }
+        Ok(index)
+    }
+
+    /// Hash of every active entity file's path, size and mtime, so long-lived handles
+    /// notice creates, edits, moves and deletes made by other processes.
+    fn entity_files_stamp(&self) -> Result<u64> {
+        // Combine per-file hashes with a commutative sum so directory order doesn't matter.
+        let mut stamp = 0u64;
+        for kind in EntityKind::ALL {
+            let dir = self.paths.kind_dir(kind);
+            if !dir.exists() {
+                continue;
+            }
+            for entry in std::fs::read_dir(&dir)
+                .with_context(|| format!("Failed to read {}", dir.display()))?
+            {
+                let entry = entry?;
+                let meta = entry.metadata()?;
+                let mut hasher = DefaultHasher::new();
+                (entry.path(), meta.len(), meta.modified().ok()).hash(&mut hasher);
+                stamp = stamp.wrapping_add(hasher.finish());
+            }
+        }
+        Ok(stamp)
+    }
+
+    /// Forget the alias index after entities are removed or renamed.
+    fn invalidate_aliases(&self) {
+        *self.aliases.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Whether a frontmatter key names a relationship under the repo's `[relationships]` config.
+    #[must_use]
+    pub fn is_relationship_key(&self, key: &str) -> bool {
+        let key = key.trim();
+        if key.is_empty() || METADATA_KEYS.contains(&key) {
+            return false;
+        }
+
+        let config = &self.config.relationships;
+        if config.metadata_keys.iter().any(|k| k.trim() == key) {
+            return false;
+        }
+
+        config
+            .allowed
+            .as_ref()
+            .is_none_or(|allowed| allowed.iter().any(|k| k.trim() == key))
+    }
+
+    /// Relationships declared in `frontmatter`, in frontmatter order.
+    #[must_use]
+    pub fn extract_relationships(&self, frontmatter: &Mapping) -> Vec<(String, Vec<String>)> {
+        self.extract_relationship_targets(frontmatter)
+            .into_iter()
+            .map(|(rel, targets)| (rel, targets.into_iter().map(|t| t.id).collect()))
+            .collect()
+    }
+
+    /// Like [`Self::extract_relationships`], but keeps each target's qualifiers.
+    #[must_use]
+    pub fn extract_relationship_targets(
+        &self,
+        frontmatter: &Mapping,
+    ) -> Vec<(String, Vec<RelationTarget>)> {
+        let mut rels = Vec::new();
+
+        for (key, value) in frontmatter {
+            let Value::String(key) = key else {
+                continue;
+            };
+
+            if !self.is_relationship_key(key) {
+                continue;
+            }
+
+            let targets = match value {
+                Value::Sequence(seq) => seq.iter().filter_map(relation_target).collect(),
+                other => relation_target(other).into_iter().collect::<Vec<_>>(),
+            };
+
+            if targets.is_empty() {
+                continue;
+            }
+
+            rels.push((key.clone(), targets));
+        }
+
+        rels
+    }
+
     pub fn read_raw(&self, id: &str) -> Result<String> {
+        let id = &self.resolve_id(id)?;
         let path = self
             .paths
             .entity_path(id)
@@ -231,7 +588,70 @@ impl IxchelRepo {
         std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
     }
 
+    /// Read and parse a single entity by canonical id.
+    pub fn read_document(&self, id: &str) -> Result<MarkdownDocument> {
+        let path = self
+            .paths
+            .entity_path(id)
+            .with_context(|| format!("Unknown entity id prefix: {id}"))?;
+        let raw = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {id} ({})", path.display()))?;
+        parse_markdown(&path, &raw).with_context(|| format!("Failed to parse {id}"))
+    }
+
+    /// Read and parse many entities on a bounded pool of threads.
+    ///
+    /// Results come back in the same order as `ids`, and each error names the id that failed.
+    #[must_use]
+    pub fn read_documents(&self, ids: &[String]) -> Vec<Result<MarkdownDocument>> {
+        let workers = std::thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(MAX_READ_THREADS)
+            .min(ids.len());
+        if workers <= 1 {
+            return ids.iter().map(|id| self.read_document(id)).collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let mut results = ids.iter().map(|_| None).collect::<Vec<_>>();
+        std::thread::scope(|scope| {
+            let handles = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut done = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, AtomicOrdering::Relaxed);
+                            let Some(id) = ids.get(index) else {
+                                break;
+                            };
+                            done.push((index, self.read_document(id)));
+                        }
+                        done
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for handle in handles {
+                if let Ok(done) = handle.join() {
+                    for (index, result) in done {
+                        results[index] = Some(result);
+                    }
+                }
+            }
+        });
+
+        results
+            .into_iter()
+            .zip(ids)
+            .map(|(result, id)| {
+                result
+                    .unwrap_or_else(|| Err(anyhow::anyhow!("Failed to read {id}: reader panicked")))
+            })
+            .collect()
+    }
+
     pub fn delete_entity(&self, id: &str) -> Result<()> {
+        let id = &self.resolve_id(id)?;
         let path = self
             .paths
             .entity_path(id)
@@ -243,69 +663,215 @@ impl IxchelRepo {
 
         std::fs::remove_file(&path)
             .with_context(|| format!("Failed to delete {}", path.display()))?;
+        self.invalidate_aliases();
         Ok(())
     }
 
+    /// Move an entity to another kind, re-prefixing its id and rewriting inbound links.
+    ///
+    /// Every file is rendered before anything is written. The new file is written first and
+    /// the old one removed last, so an interrupted move never loses the entity. With
+    /// `dry_run`, nothing is written and the report describes the planned move.
+    pub fn move_entity(&self, id: &str, kind: EntityKind, dry_run: bool) -> Result<MoveReport> {
+        let old_id = self.resolve_id(id)?;
+        let old_path = self
+            .paths
+            .entity_path(&old_id)
+            .with_context(|| format!("Unknown entity id prefix: {old_id}"))?;
+        if !old_path.exists() {
+            anyhow::bail!("Entity does not exist: {old_id} ({})", old_path.display());
+        }
+        if kind_from_id(&old_id) == Some(kind) {
+            anyhow::bail!("{old_id} is already a {}", kind.as_str());
+        }
+
+        let (_, suffix) = ix_id::parse_id(&old_id)
+            .map_err(|err| anyhow::anyhow!("Cannot re-prefix id {old_id}: {err}"))?;
+        let new_id = format!("{}-{suffix}", kind.id_prefix());
+        let new_path = self.paths.kind_dir(kind).join(format!("{new_id}.md"));
+        if new_path.exists() {
+            anyhow::bail!("Entity already exists: {}", new_path.display());
+        }
+
+        let mut doc = self.read_document(&old_id)?;
+        if let Some(schema) = self.config.schema.get(kind.as_str()) {
+            let missing = missing_required(&doc.frontmatter, schema);
+            if !missing.is_empty() {
+                anyhow::bail!(
+                    "Cannot move {old_id} to {}: missing required field(s): {}",
+                    kind.as_str(),
+                    missing.join(", ")
+                );
+            }
+        }
+
+        let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        set_string(&mut doc.frontmatter, "id", new_id.clone());
+        set_string(&mut doc.frontmatter, "type", kind.as_str());
+        set_string(&mut doc.frontmatter, "updated_at", now.clone());
+        rewrite_relationship_target(self, &mut doc.frontmatter, &old_id, &new_id);
+        let moved = render_markdown(&doc)?;
+
+        let mut referrers = Vec::new();
+        for item in self.list_with_options(None, ListSort::default(), true)? {
+            if item.id == old_id {
+                continue;
+            }
+            let raw = std::fs::read_to_string(&item.path)
+                .with_context(|| format!("Failed to read {}", item.path.display()))?;
+            let mut doc = parse_markdown(&item.path, &raw)?;
+            if rewrite_relationship_target(self, &mut doc.frontmatter, &old_id, &new_id) {
+                set_string(&mut doc.frontmatter, "updated_at", now.clone());
+                referrers.push((item.id, item.path, render_markdown(&doc)?));
+            }
+        }
+
+        if !dry_run {
+            write_entity_atomic(&new_path, &moved)?;
+            for (_, path, out) in &referrers {
+                write_entity_atomic(path, out)?;
+            }
+            std::fs::remove_file(&old_path)
+                .with_context(|| format!("Failed to delete {}", old_path.display()))?;
+            self.invalidate_aliases();
+        }
+
+        Ok(MoveReport {
+            old_id,
+            new_id,
+            kind,
+            old_path,
+            new_path,
+            rewritten: referrers.into_iter().map(|(id, _, _)| id).collect(),
+        })
+    }
+
+    pub fn archive_entity(&self, id: &str) -> Result<PathBuf> {
+        let id = &self.resolve_id(id)?;
+        let path = self
+            .paths
+            .entity_path(id)
+            .with_context(|| format!("Unknown entity id prefix: {id}"))?;
+        let archived_path = self
+            .paths
+            .archived_entity_path(id)
+            .with_context(|| format!("Unknown entity id prefix: {id}"))?;
+
+        if !path.exists() {
+            if archived_path.exists() {
+                anyhow::bail!(
+                    "Entity is already archived: {id} ({})",
+                    archived_path.display()
+                );
+            }
+            anyhow::bail!("Entity does not exist: {id} ({})", path.display());
+        }
+
+        let raw = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut doc = parse_markdown(&path, &raw)?;
+
+        let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        set_string(&mut doc.frontmatter, "status", "archived");
+        set_string(&mut doc.frontmatter, "archived_at", now.clone());
+        set_string(&mut doc.frontmatter, "updated_at", now);
+
+        if let Some(parent) = archived_path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let out = render_markdown(&doc)?;
+        write_entity_atomic(&archived_path, &out)?;
+        std::fs::remove_file(&path)
+            .with_context(|| format!("Failed to delete {}", path.display()))?;
+        self.invalidate_aliases();
+
+        Ok(archived_path)
+    }
+
     pub fn list(&self, kind: Option<EntityKind>, sort: ListSort) -> Result<Vec<EntitySummary>> {
+        self.list_entries(kind, sort, true, false)
+    }
+
+    pub fn list_with_options(
+        &self,
+        kind: Option<EntityKind>,
+        sort: ListSort,
+        include_archived: bool,
+    ) -> Result<Vec<EntitySummary>> {
+        self.list_entries(kind, sort, true, include_archived)
+    }
+
+    pub fn list_archived(
+        &self,
+        kind: Option<EntityKind>,
+        sort: ListSort,
+    ) -> Result<Vec<EntitySummary>> {
+        self.list_entries(kind, sort, false, true)
+    }
+
+    fn list_entries(
+        &self,
+        kind: Option<EntityKind>,
+        sort: ListSort,
+        active: bool,
+        archived: bool,
+    ) -> Result<Vec<EntitySummary>> {
         let mut out = Vec::new();
 
-        let kinds: Vec<EntityKind> = kind.map_or_else(
-            || {
-                vec![
-                    EntityKind::Decision,
-                    EntityKind::Issue,
-                    EntityKind::Idea,
-                    EntityKind::Report,
-                    EntityKind::Source,
-                    EntityKind::Citation,
-                    EntityKind::Agent,
-                    EntityKind::Session,
-                ]
-            },
-            |k| vec![k],
-        );
+        let kinds: Vec<EntityKind> = kind.map_or_else(|| EntityKind::ALL.to_vec(), |k| vec![k]);
 
         for kind in kinds {
-            let dir = self.paths.kind_dir(kind);
-            if !dir.exists() {
-                continue;
+            let mut dirs = Vec::new();
+            if active {
+                dirs.push(self.paths.kind_dir(kind));
+            }
+            if archived {
+                dirs.push(self.paths.archive_kind_dir(kind));
             }
 
-            for entry in std::fs::read_dir(&dir)
-                .with_context(|| format!("Failed to read {}", dir.display()))?
-            {
-                let entry = entry?;
-                let path = entry.path();
-                if path.extension().and_then(|s| s.to_str()) != Some("md") {
+            for dir in dirs {
+                if !dir.exists() {
                     continue;
                 }
 
-                let raw = std::fs::read_to_string(&path)
-                    .with_context(|| format!("Failed to read {}", path.display()))?;
-                let doc = parse_markdown(&path, &raw)?;
-
-                let id = get_string(&doc.frontmatter, "id")
-                    .or_else(|| {
-                        path.file_stem()
-                            .and_then(|s| s.to_str())
-                            .map(std::string::ToString::to_string)
-                    })
-                    .unwrap_or_default();
-                let title = get_string(&doc.frontmatter, "title").unwrap_or_default();
+                for entry in std::fs::read_dir(&dir)
+                    .with_context(|| format!("Failed to read {}", dir.display()))?
+                {
+                    let entry = entry?;
+                    let path = entry.path();
+                    if path.extension().and_then(|s| s.to_str()) != Some("md") {
+                        continue;
+                    }
 
-                let summary = EntitySummary {
-                    id,
-                    kind,
-                    title,
-                    path,
-                };
+                    let raw = std::fs::read_to_string(&path)
+                        .with_context(|| format!("Failed to read {}", path.display()))?;
+                    let doc = parse_markdown(&path, &raw)?;
+
+                    let id = get_string(&doc.frontmatter, "id")
+                        .or_else(|| {
+                            path.file_stem()
+                                .and_then(|s| s.to_str())
+                                .map(std::string::ToString::to_string)
+                        })
+                        .unwrap_or_default();
+                    let title = get_string(&doc.frontmatter, "title").unwrap_or_default();
+
+                    let summary = EntitySummary {
+                        id,
+                        kind,
+                        title,
+                        path,
+                    };
 
-                let sort_ts = match sort {
-                    ListSort::CreatedDesc => parse_timestamp(&doc.frontmatter, "created_at"),
-                    ListSort::UpdatedDesc => parse_timestamp(&doc.frontmatter, "updated_at"),
-                };
+                    let sort_ts = match sort {
+                        ListSort::CreatedDesc => parse_timestamp(&doc.frontmatter, "created_at"),
+                        ListSort::UpdatedDesc => parse_timestamp(&doc.frontmatter, "updated_at"),
+                    };
 
-                out.push(ListEntry { summary, sort_ts });
+                    out.push(ListEntry { summary, sort_ts });
+                }
             }
         }
 
@@ -321,20 +887,61 @@ impl IxchelRepo {
         Ok(out.into_iter().map(|entry| entry.summary).collect())
     }
 
+    /// Map each tag to the ids carrying it.
+    ///
+    /// Tags are grouped case-insensitively and keyed by the first spelling seen in id order.
     pub fn collect_tags(&self, kind: Option<EntityKind>) -> Result<HashMap<String, Vec<String>>> {
-        let mut out: HashMap<String, Vec<String>> = HashMap::new();
+        let mut groups: HashMap<String, (String, Vec<String>)> = HashMap::new();
 
-        for item in self.list(kind, ListSort::default())? {
+        let mut items = self.list(kind, ListSort::default())?;
+        items.sort_by(|a, b| a.id.cmp(&b.id));
+        for item in items {
             let raw = std::fs::read_to_string(&item.path)
                 .with_context(|| format!("Failed to read {}", item.path.display()))?;
             let doc = parse_markdown(&item.path, &raw)?;
-            let tags = normalized_tags_vec(&doc.frontmatter);
-            if tags.is_empty() {
+            for tag in normalized_tags_vec(&doc.frontmatter) {
+                let (_, ids) = groups
+                    .entry(tag.to_lowercase())
+                    .or_insert_with(|| (tag.clone(), Vec::new()));
+                if ids.last() != Some(&item.id) {
+                    ids.push(item.id.clone());
+                }
+            }
+        }
+
+        Ok(groups.into_values().collect())
+    }
+
+    /// List entities carrying every tag in `tags` (case-insensitive).
+    pub fn list_tagged(
+        &self,
+        kind: Option<EntityKind>,
+        sort: ListSort,
+        include_archived: bool,
+        tags: &[String],
+    ) -> Result<Vec<EntitySummary>> {
+        let wanted = tags
+            .iter()
+            .filter_map(|tag| normalize_tag(tag))
+            .map(|tag| tag.to_lowercase())
+            .collect::<BTreeSet<_>>();
+
+        let mut out = Vec::new();
+        for item in self.list_with_options(kind, sort, include_archived)? {
+            if wanted.is_empty() {
+                out.push(item);
                 continue;
             }
 
-            for tag in tags {
-                out.entry(tag).or_default().push(item.id.clone());
+            let raw = std::fs::read_to_string(&item.path)
+                .with_context(|| format!("Failed to read {}", item.path.display()))?;
+            let doc = parse_markdown(&item.path, &raw)?;
+            let have = normalized_tags_vec(&doc.frontmatter)
+                .into_iter()
+                .map(|tag| tag.to_lowercase())
+                .collect::<BTreeSet<_>>();
+            if wanted.is_subset(&have) {
+                out.push(item);
             }
         }
 
@@ -358,6 +965,7 @@ impl IxchelRepo {
     }
 
     pub fn add_tags(&self, id: &str, tags: &[String]) -> Result<bool> {
+        let id = &self.resolve_id(id)?;
         let path = self
             .paths
             .entity_path(id)
@@ -372,7 +980,10 @@ impl IxchelRepo {
             let Some(tag) = normalize_tag(tag) else {
                 continue;
             };
-            if existing.iter().any(|value| value == &tag) {
+            if existing
+                .iter()
+                .any(|value| value.eq_ignore_ascii_case(&tag))
+            {
                 continue;
             }
             existing.push(tag);
@@ -392,12 +1003,12 @@ impl IxchelRepo {
         );
 
         let out = render_markdown(&doc)?;
-        std::fs::write(&path, out)
-            .with_context(|| format!("Failed to write {}", path.display()))?;
+        write_entity_atomic(&path, &out)?;
         Ok(true)
     }
 
     pub fn remove_tags(&self, id: &str, tags: &[String]) -> Result<bool> {
+        let id = &self.resolve_id(id)?;
         let path = self
             .paths
             .entity_path(id)
@@ -406,17 +1017,18 @@ impl IxchelRepo {
             .with_context(|| format!("Failed to read {}", path.display()))?;
         let mut doc = parse_markdown(&path, &raw)?;
 
+        // Tags are matched case-insensitively, as `ixchel tags` counts them.
         let to_remove = tags
             .iter()
             .filter_map(|tag| normalize_tag(tag))
-            .collect::<BTreeSet<_>>();
+            .collect::<Vec<_>>();
         if to_remove.is_empty() {
             return Ok(false);
         }
 
         let mut existing = normalized_tags_vec(&doc.frontmatter);
         let before_len = existing.len();
-        existing.retain(|tag| !to_remove.contains(tag));
+        existing.retain(|tag| !to_remove.iter().any(|r| r.eq_ignore_ascii_case(tag)));
 
         if existing.len() == before_len {
             return Ok(false);
@@ -431,34 +1043,28 @@ impl IxchelRepo {
         );
 
         let out = render_markdown(&doc)?;
-        std::fs::write(&path, out)
-            .with_context(|| format!("Failed to write {}", path.display()))?;
+        write_entity_atomic(&path, &out)?;
         Ok(true)
     }
 
     pub fn link(&self, from_id: &str, rel: &str, to_id: &str) -> Result<()> {
-        let from_path = self
-            .paths
-            .entity_path(from_id)
-            .with_context(|| format!("Unknown entity id prefix: {from_id}"))?;
-        let to_path = self
-            .paths
-            .entity_path(to_id)
-            .with_context(|| format!("Unknown entity id prefix: {to_id}"))?;
+        self.link_with_options(from_id, rel, to_id, false)
+    }
 
-        if !to_path.exists() {
-            anyhow::bail!("Target does not exist: {to_id} ({})", to_path.display());
-        }
+    pub fn link_with_options(
+        &self,
+        from_id: &str,
+        rel: &str,
+        to_id: &str,
+        allow_dangling: bool,
+    ) -> Result<()> {
+        let (from_path, to_id) = self.resolve_link_endpoints(from_id, to_id, allow_dangling)?;
 
         let raw = std::fs::read_to_string(&from_path)
             .with_context(|| format!("Failed to read {}", from_path.display()))?;
         let mut doc = parse_markdown(&from_path, &raw)?;
 
-        let mut values = get_string_list(&doc.frontmatter, rel);
-        if !values.iter().any(|v| v == to_id) {
-            values.push(to_id.to_string());
-        }
-        set_string_list(&mut doc.frontmatter, rel, values);
+        push_relation_target(&mut doc.frontmatter, rel, &to_id);
 
         let now = Utc::now();
         set_string(
@@ -468,12 +1074,111 @@ impl IxchelRepo {
         );
 
         let out = render_markdown(&doc)?;
-        std::fs::write(&from_path, out)
-            .with_context(|| format!("Failed to write {}", from_path.display()))?;
+        write_entity_atomic(&from_path, &out)?;
         Ok(())
     }
 
+    /// Apply many links in one pass, rewriting each source entity at most once.
+    ///
+    /// Invalid requests are collected in the report rather than aborting the batch. With
+    /// `strict`, every request is validated and every source rendered before anything is
+    /// written, so a bad request or unreadable source writes nothing. A filesystem error
+    /// while writing can still leave the sources written before it updated.
+    pub fn link_many(
+        &self,
+        links: &[LinkRequest],
+        allow_dangling: bool,
+        strict: bool,
+    ) -> Result<LinkBatchReport> {
+        let mut report = LinkBatchReport::default();
+        let mut by_source = BTreeMap::<PathBuf, Vec<PendingLink<'_>>>::new();
+
+        for (index, link) in links.iter().enumerate() {
+            match self.resolve_link_endpoints(&link.from, &link.to, allow_dangling) {
+                Ok((from_path, to_id)) => {
+                    by_source
+                        .entry(from_path)
+                        .or_default()
+                        .push((index, link.rel.as_str(), to_id));
+                }
+                Err(err) => {
+                    if strict {
+                        return Err(err.context(format!("link #{}", index + 1)));
+                    }
+                    report.errors.push(LinkBatchError {
+                        index,
+                        message: format!("{err:#}"),
+                        missing_target: err.downcast_ref::<LinkError>().is_some(),
+                    });
+                }
+            }
+        }
+
+        let mut staged = Vec::with_capacity(by_source.len());
+        for (from_path, edges) in by_source {
+            match render_links(&from_path, &edges) {
+                Ok((added, rendered)) => staged.push((from_path, edges, added, rendered)),
+                Err(err) if strict => {
+                    return Err(err.context(format!("link #{}", edges[0].0 + 1)));
+                }
+                Err(err) => report.errors.extend(batch_errors(&edges, &err)),
+            }
+        }
+
+        for (from_path, edges, added, rendered) in staged {
+            if let Some(out) = rendered
+                && let Err(err) = write_entity_atomic(&from_path, &out)
+            {
+                if strict {
+                    return Err(err.context(format!("link #{}", edges[0].0 + 1)));
+                }
+                report.errors.extend(batch_errors(&edges, &err));
+                continue;
+            }
+            report.added += added;
+            report.skipped += edges.len() - added;
+        }
+
+        report.errors.sort_by_key(|e| e.index);
+        Ok(report)
+    }
+
+    /// Resolve both ends of a link, returning the source path and canonical target id.
+    fn resolve_link_endpoints(
+        &self,
+        from_id: &str,
+        to_id: &str,
+        allow_dangling: bool,
+    ) -> Result<(PathBuf, String)> {
+        let from_id = self.resolve_id(from_id)?;
+        let to_id = self.resolve_id(to_id)?;
+        let from_path = self
+            .paths
+            .entity_path(&from_id)
+            .with_context(|| format!("Unknown entity id prefix: {from_id}"))?;
+        let to_path = self
+            .paths
+            .entity_path(&to_id)
+            .with_context(|| format!("Unknown entity id prefix: {to_id}"))?;
+
+        if !from_path.exists() {
+            anyhow::bail!("Source does not exist: {from_id} ({})", from_path.display());
+        }
+
+        if !allow_dangling && !to_path.exists() {
+            return Err(LinkError::MissingTarget {
+                id: to_id,
+                path: to_path,
+            }
+            .into());
+        }
+
+        Ok((from_path, to_id))
+    }
+
     pub fn unlink(&self, from_id: &str, rel: &str, to_id: &str) -> Result<bool> {
+        let from_id = &self.resolve_id(from_id)?;
+        let to_id = &self.resolve_id(to_id)?;
         let from_path = self
             .paths
             .entity_path(from_id)
@@ -483,9 +1188,9 @@ impl IxchelRepo {
             .with_context(|| format!("Failed to read {}", from_path.display()))?;
         let mut doc = parse_markdown(&from_path, &raw)?;
 
-        let mut values = get_string_list(&doc.frontmatter, rel);
+        let mut values = relation_values(&doc.frontmatter, rel);
         let before_len = values.len();
-        values.retain(|v| v != to_id);
+        values.retain(|v| relation_value_id(v) != Some(to_id.as_str()));
 
         if values.len() == before_len {
             return Ok(false);
@@ -494,7 +1199,8 @@ impl IxchelRepo {
         if values.is_empty() {
             doc.frontmatter.remove(Value::String(rel.to_string()));
         } else {
-            set_string_list(&mut doc.frontmatter, rel, values);
+            doc.frontmatter
+                .insert(Value::String(rel.to_string()), Value::Sequence(values));
         }
 
         let now = Utc::now();
@@ -505,8 +1211,7 @@ impl IxchelRepo {
         );
 
         let out = render_markdown(&doc)?;
-        std::fs::write(&from_path, out)
-            .with_context(|| format!("Failed to write {}", from_path.display()))?;
+        write_entity_atomic(&from_path, &out)?;
 
         Ok(true)
     }
@@ -525,22 +1230,107 @@ impl IxchelRepo {
         })
     }
 
+    /// Repository-level health checks: no dangling links.
+    ///
+    /// Index and embedder checks live in `ix-app`, which knows about storage backends.
+    #[must_use]
+    pub fn health_check(&self) -> Vec<HealthCheck> {
+        let links = match self.check_with_suggestions() {
+            Ok(report) => {
+                let broken = report
+                    .errors
+                    .iter()
+                    .filter(|issue| issue.kind == CheckIssueKind::BrokenLink)
+                    .count();
+                if broken == 0 {
+                    HealthCheck::pass("links", "no dangling links")
+                } else {
+                    HealthCheck::fail(
+                        "links",
+                        format!("{broken} dangling link(s)"),
+                        "Run `ixchel check` to list them, then create the targets or unlink.",
+                    )
+                }
+            }
+            Err(err) => HealthCheck::fail(
+                "links",
+                format!("{err:#}"),
+                "Run `ixchel check` for details.",
+            ),
+        };
+
+        vec![links]
+    }
+
+    /// Check every active entity against its kind's configured schema only.
+    ///
+    /// Unlike [`Self::check`], links, ids and paths are not validated. Entities whose kind
+    /// has no schema are returned with `has_schema: false`.
+    pub fn validate_schema(&self) -> Result<Vec<SchemaCheck>> {
+        let mut out = Vec::new();
+        for kind in EntityKind::ALL {
+            let dir = self.paths.kind_dir(kind);
+            if !dir.exists() {
+                continue;
+            }
+
+            let mut entries = Vec::new();
+            for entry in std::fs::read_dir(&dir)
+                .with_context(|| format!("Failed to read {}", dir.display()))?
+            {
+                let path = entry?.path();
+                if path.extension().and_then(|s| s.to_str()) == Some("md") {
+                    entries.push(path);
+                }
+            }
+            entries.sort();
+
+            let schema = self.config.schema.get(kind.as_str());
+            for path in entries {
+                let id = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or_default()
+                    .to_string();
+                let Some(schema) = schema else {
+                    out.push(SchemaCheck {
+                        id,
+                        kind,
+                        path,
+                        has_schema: false,
+                        violations: Vec::new(),
+                    });
+                    continue;
+                };
+
+                let raw = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                let violations = match parse_markdown(&path, &raw) {
+                    Ok(doc) => schema_violations(&doc.frontmatter, schema),
+                    Err(err) => vec![SchemaViolation {
+                        field: "frontmatter".to_string(),
+                        message: err.to_string(),
+                        suggestion: Some("Run `ixchel check` for parse diagnostics.".to_string()),
+                    }],
+                };
+                out.push(SchemaCheck {
+                    id,
+                    kind,
+                    path,
+                    has_schema: true,
+                    violations,
+                });
+            }
+        }
+
+        Ok(out)
+    }
+
     pub fn check_with_suggestions(&self) -> Result<CheckReportDetailed> {
         let mut errors = Vec::new();
         let mut seen_ids: BTreeSet<String> = BTreeSet::new();
 
-        let kinds = [
-            EntityKind::Decision,
-            EntityKind::Issue,
-            EntityKind::Idea,
-            EntityKind::Report,
-            EntityKind::Source,
-            EntityKind::Citation,
-            EntityKind::Agent,
-            EntityKind::Session,
-        ];
-
-        for kind in kinds {
+        for kind in EntityKind::ALL {
             let dir = self.paths.kind_dir(kind);
             if !dir.exists() {
                 continue;
@@ -560,7 +1350,7 @@ impl IxchelRepo {
 
             entries.sort();
             for path in entries {
-                check_document(&self.paths, kind, &path, &mut seen_ids, &mut errors)?;
+                check_document(self, kind, &path, &mut seen_ids, &mut errors)?;
             }
         }
 
@@ -568,8 +1358,19 @@ impl IxchelRepo {
     }
 }
 
+fn load_repo_config(ixchel_dir: PathBuf, config_path: Option<&Path>) -> Result<IxchelConfig> {
+    let mut loader = ConfigLoader::new("").with_project_dir(ixchel_dir);
+    let env_path = std::env::var_os(CONFIG_ENV_VAR)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from);
+    if let Some(path) = config_path.map(Path::to_path_buf).or(env_path) {
+        loader = loader.with_config_file(path);
+    }
+    Ok(loader.load()?)
+}
+
 fn check_document(
-    paths: &IxchelPaths,
+    repo: &IxchelRepo,
     kind: EntityKind,
     path: &Path,
     seen_ids: &mut BTreeSet<String>,
@@ -606,8 +1407,14 @@ fn check_document(
     check_id_and_path(resolved_id, kind, path, seen_ids, errors);
 
     if let Some(frontmatter) = frontmatter {
-        check_frontmatter_fields(frontmatter, kind, path, errors);
-        check_relationships(paths, frontmatter, path, errors);
+        let default_schema = KindSchemaConfig::default();
+        let schema = repo
+            .config
+            .schema
+            .get(kind.as_str())
+            .unwrap_or(&default_schema);
+        check_frontmatter_fields(frontmatter, kind, schema, path, errors);
+        check_relationships(repo, frontmatter, path, errors);
     }
 
     Ok(())
@@ -770,6 +1577,7 @@ fn check_id_and_path(
 fn check_frontmatter_fields(
     frontmatter: &Mapping,
     kind: EntityKind,
+    schema: &KindSchemaConfig,
     path: &Path,
     errors: &mut Vec<CheckIssue>,
 ) {
@@ -781,6 +1589,224 @@ fn check_frontmatter_fields(
     check_optional_string_field(frontmatter, "status", path, errors);
     check_optional_string_field(frontmatter, "created_by", path, errors);
     check_optional_string_field(frontmatter, "date", path, errors);
+    check_status_value(frontmatter, schema, path, errors);
+    check_date_value(frontmatter, schema, path, errors);
+}
+
+fn check_status_value(
+    frontmatter: &Mapping,
+    schema: &KindSchemaConfig,
+    path: &Path,
+    errors: &mut Vec<CheckIssue>,
+) {
+    if let Some((message, suggestion)) = status_issue(frontmatter, schema) {
+        push_issue(errors, path, message, Some(suggestion));
+    }
+}
+
+fn check_date_value(
+    frontmatter: &Mapping,
+    schema: &KindSchemaConfig,
+    path: &Path,
+    errors: &mut Vec<CheckIssue>,
+) {
+    if let Some((message, suggestion)) = date_issue(frontmatter, schema) {
+        push_issue(errors, path, message, Some(suggestion));
+    }
+}
+
+fn status_issue(frontmatter: &Mapping, schema: &KindSchemaConfig) -> Option<(String, String)> {
+    let (Some(allowed), Some(Value::String(value))) = (
+        schema.statuses.as_ref(),
+        frontmatter.get(Value::String("status".to_string())),
+    ) else {
+        return None;
+    };
+
+    // Archiving always sets this status, regardless of the configured set.
+    let value = value.trim();
+    if value == "archived" || allowed.iter().any(|status| status.trim() == value) {
+        return None;
+    }
+
+    Some((
+        format!("invalid status '{value}' in frontmatter key 'status'"),
+        format!("Set `status` to one of: {}.", allowed.join(", ")),
+    ))
+}
+
+fn date_issue(frontmatter: &Mapping, schema: &KindSchemaConfig) -> Option<(String, String)> {
+    let Some(Value::String(value)) = frontmatter.get(Value::String("date".to_string())) else {
+        return None;
+    };
+
+    let format = schema.date_format.as_deref().unwrap_or(DEFAULT_DATE_FORMAT);
+    if NaiveDate::parse_from_str(value.trim(), format).is_ok() {
+        return None;
+    }
+    Some((
+        format!("invalid date '{value}' in frontmatter key 'date'"),
+        format!("Set `date` using the format `{format}`."),
+    ))
+}
+
+fn missing_required(frontmatter: &Mapping, schema: &KindSchemaConfig) -> Vec<String> {
+    schema
+        .required
+        .iter()
+        .filter(|key| match frontmatter.get(Value::String((*key).clone())) {
+            None | Some(Value::Null) => true,
+            Some(Value::String(value)) => value.trim().is_empty(),
+            Some(Value::Sequence(values)) => values.is_empty(),
+            Some(_) => false,
+        })
+        .cloned()
+        .collect()
+}
+
+/// Replace `old_id` with `new_id` in every relationship value; returns whether anything changed.
+fn rewrite_relationship_target(
+    repo: &IxchelRepo,
+    frontmatter: &mut Mapping,
+    old_id: &str,
+    new_id: &str,
+) -> bool {
+    let mut changed = false;
+    for (key, value) in frontmatter.iter_mut() {
+        let Value::String(key) = key else {
+            continue;
+        };
+        if !repo.is_relationship_key(key) {
+            continue;
+        }
+        match value {
+            Value::Sequence(targets) => {
+                for target in targets {
+                    changed |= retarget_relation_value(target, old_id, new_id);
+                }
+            }
+            other => changed |= retarget_relation_value(other, old_id, new_id),
+        }
+    }
+    changed
+}
+
+fn retarget_relation_value(value: &mut Value, old_id: &str, new_id: &str) -> bool {
+    let target = match value {
+        Value::String(target) => target,
+        Value::Mapping(map) => match map.get_mut(Value::String("id".to_string())) {
+            Some(Value::String(target)) => target,
+            _ => return false,
+        },
+        _ => return false,
+    };
+    if target.trim() != old_id {
+        return false;
+    }
+    *target = new_id.to_string();
+    true
+}
+
+/// Parse one relationship entry: a bare id or a mapping with an `id` key plus qualifiers.
+fn relation_target(value: &Value) -> Option<RelationTarget> {
+    let (id, properties) = match value {
+        Value::String(id) => (id.clone(), BTreeMap::new()),
+        Value::Mapping(map) => {
+            let id = get_string(map, "id")?;
+            let properties = map
+                .iter()
+                .filter_map(|(key, value)| {
+                    let Value::String(key) = key else {
+                        return None;
+                    };
+                    if key == "id" {
+                        return None;
+                    }
+                    let value = match value {
+                        Value::String(s) => s.clone(),
+                        Value::Bool(b) => b.to_string(),
+                        Value::Number(n) => n.to_string(),
+                        _ => return None,
+                    };
+                    Some((key.clone(), value))
+                })
+                .collect();
+            (id, properties)
+        }
+        _ => return None,
+    };
+
+    looks_like_entity_id(&id).then_some(RelationTarget { id, properties })
+}
+
+/// Target id of a raw relationship entry, bare or qualified.
+fn relation_value_id(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(id) => Some(id),
+        Value::Mapping(map) => match map.get(Value::String("id".to_string()))? {
+            Value::String(id) => Some(id),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Raw entries of relationship `rel`, keeping qualified (mapping) targets intact.
+fn relation_values(frontmatter: &Mapping, rel: &str) -> Vec<Value> {
+    match frontmatter.get(Value::String(rel.to_string())) {
+        Some(Value::Sequence(seq)) => seq.clone(),
+        Some(value @ (Value::String(_) | Value::Mapping(_))) => vec![value.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Append a bare `to_id` to `rel` unless an entry already targets it; returns whether it was added.
+fn push_relation_target(frontmatter: &mut Mapping, rel: &str, to_id: &str) -> bool {
+    let mut values = relation_values(frontmatter, rel);
+    if values.iter().any(|v| relation_value_id(v) == Some(to_id)) {
+        return false;
+    }
+    values.push(Value::String(to_id.to_string()));
+    frontmatter.insert(Value::String(rel.to_string()), Value::Sequence(values));
+    true
+}
+
+fn schema_violations(frontmatter: &Mapping, schema: &KindSchemaConfig) -> Vec<SchemaViolation> {
+    let mut violations = Vec::new();
+
+    for key in missing_required(frontmatter, schema) {
+        violations.push(SchemaViolation {
+            message: format!("missing required frontmatter key '{key}'"),
+            suggestion: Some(format!("Add a non-empty `{key}` to frontmatter.")),
+            field: key,
+        });
+    }
+
+    for key in ["status", "date"] {
+        match frontmatter.get(Value::String(key.to_string())) {
+            None | Some(Value::Null | Value::String(_)) => {}
+            Some(_) => violations.push(SchemaViolation {
+                field: key.to_string(),
+                message: format!("expected a string in frontmatter key '{key}'"),
+                suggestion: Some(format!("Quote the `{key}` value.")),
+            }),
+        }
+    }
+
+    for (field, issue) in [
+        ("status", status_issue(frontmatter, schema)),
+        ("date", date_issue(frontmatter, schema)),
+    ] {
+        if let Some((message, suggestion)) = issue {
+            violations.push(SchemaViolation {
+                field: field.to_string(),
+                message,
+                suggestion: Some(suggestion),
+            });
+        }
+    }
+
+    violations
 }
 
 fn check_frontmatter_type(
@@ -889,7 +1915,7 @@ fn check_timestamp(frontmatter: &Mapping, key: &str, path: &Path, errors: &mut V
                 push_issue(
                     errors,
                     path,
-                    format!("{key} is not RFC3339"),
+                    format!("invalid date '{value}' in frontmatter key '{key}'"),
                     Some(format!(
                         "Set `{key}` to an RFC3339 timestamp, for example `2024-01-01T00:00:00Z`.",
                     )),
@@ -956,12 +1982,13 @@ fn check_optional_string_field(
 }
 
 fn check_relationships(
-    paths: &IxchelPaths,
+    repo: &IxchelRepo,
     frontmatter: &Mapping,
     path: &Path,
     errors: &mut Vec<CheckIssue>,
 ) {
-    for (rel, targets) in extract_relationships(frontmatter) {
+    let paths = &repo.paths;
+    for (rel, targets) in repo.extract_relationships(frontmatter) {
         for target in targets {
             let Some(target_path) = paths.entity_path(&target) else {
                 push_issue(
@@ -975,17 +2002,20 @@ fn check_relationships(
                 continue;
             };
 
-            if !target_path.exists() {
+            let archived = paths
+                .archived_entity_path(&target)
+                .is_some_and(|archived_path| archived_path.exists());
+            if !target_path.exists() && !archived {
                 let suggestion = format!(
                     "Create `{}` or remove `{rel}` -> `{target}`.",
                     target_path.display()
                 );
-                push_issue(
-                    errors,
-                    path,
-                    format!("broken link {rel} -> {target}"),
-                    Some(suggestion),
-                );
+                errors.push(CheckIssue {
+                    path: path.to_path_buf(),
+                    kind: CheckIssueKind::BrokenLink,
+                    message: format!("broken link {rel} -> {target}"),
+                    suggestion: Some(suggestion),
+                });
             }
         }
     }
@@ -999,6 +2029,7 @@ fn push_issue(
 ) {
     errors.push(CheckIssue {
         path: path.to_path_buf(),
+        kind: CheckIssueKind::Other,
         message: message.into(),
         suggestion,
     });
@@ -1051,6 +2082,163 @@ fn default_template(kind: EntityKind) -> String {
     }
 }
 
+const EXAMPLE_DECISION_ID: &str = "dec-000001";
+
+const EXAMPLE_CONFIG_HEADER: &str = "\
+# Ixchel project config. Global settings in ~/.ixchel/config/config.toml also apply.
+# relationships.metadata_keys lists frontmatter keys that hold ids but are not relationships.
+# Set relationships.allowed to a list of keys to treat only those keys as relationships.
+";
+
+const EXAMPLE_DECISION_STATUSES: [&str; 5] = [
+    "proposed",
+    "accepted",
+    "rejected",
+    "superseded",
+    "deprecated",
+];
+
+/// Renders the `--with-examples` config from [`IxchelConfig::default`].
+fn example_config() -> Result<String> {
+    let mut config = IxchelConfig::default();
+    config.relationships.metadata_keys = vec!["owner".to_string()];
+    config.schema.insert(
+        EntityKind::Decision.as_str().to_string(),
+        KindSchemaConfig {
+            statuses: Some(
+                EXAMPLE_DECISION_STATUSES
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            date_format: Some(DEFAULT_DATE_FORMAT.to_string()),
+            ..KindSchemaConfig::default()
+        },
+    );
+    let body = config
+        .to_toml()
+        .context("Failed to serialize example config")?;
+    Ok(format!("{EXAMPLE_CONFIG_HEADER}\n{body}"))
+}
+
+const EXAMPLE_DECISION_BODY: &str = "## Context\n\nWe need a durable, reviewable record of why the project looks the way it does.\n\n## Decision\n\nRecord significant decisions as Markdown files under `.ixchel/decisions/`, and link\nrelated issues and sources through frontmatter keys such as `implements` or `cites`.\n\n## Consequences\n\nDecisions are versioned with the code. Run `ixchel check` to validate them and\n`ixchel sync` to make them searchable.\n";
+
+fn write_example_decision(paths: &IxchelPaths, force: bool) -> Result<()> {
+    let path = paths
+        .entity_path(EXAMPLE_DECISION_ID)
+        .with_context(|| format!("Unknown entity id prefix: {EXAMPLE_DECISION_ID}"))?;
+    if path.exists() && !force {
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let timestamp = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let mut frontmatter = Mapping::new();
+    set_string(&mut frontmatter, "id", EXAMPLE_DECISION_ID);
+    set_string(&mut frontmatter, "type", EntityKind::Decision.as_str());
+    set_string(&mut frontmatter, "title", "Record architecture decisions");
+    set_string(&mut frontmatter, "status", "accepted");
+    set_string(
+        &mut frontmatter,
+        "date",
+        now.format(DEFAULT_DATE_FORMAT).to_string(),
+    );
+    set_string(&mut frontmatter, "created_at", timestamp.clone());
+    set_string(&mut frontmatter, "updated_at", timestamp);
+    set_string_list(&mut frontmatter, "tags", vec!["example".to_string()]);
+
+    let doc = MarkdownDocument {
+        frontmatter,
+        body: EXAMPLE_DECISION_BODY.to_string(),
+    };
+    let markdown = render_markdown(&doc)?;
+    write_entity_atomic(&path, &markdown)
+}
+
+/// A validated link waiting to be written: request index, relationship, target id.
+type PendingLink<'a> = (usize, &'a str, String);
+
+/// Add `edges` to the entity at `from_path`, returning how many were new and the
+/// re-rendered file, or `None` when nothing changed.
+fn render_links(from_path: &Path, edges: &[PendingLink<'_>]) -> Result<(usize, Option<String>)> {
+    let raw = std::fs::read_to_string(from_path)
+        .with_context(|| format!("Failed to read {}", from_path.display()))?;
+    let mut doc = parse_markdown(from_path, &raw)?;
+
+    let mut added = 0;
+    for (_, rel, to_id) in edges {
+        if push_relation_target(&mut doc.frontmatter, rel, to_id) {
+            added += 1;
+        }
+    }
+
+    if added == 0 {
+        return Ok((0, None));
+    }
+    let now = Utc::now();
+    set_string(
+        &mut doc.frontmatter,
+        "updated_at",
+        now.to_rfc3339_opts(SecondsFormat::Secs, true),
+    );
+    Ok((added, Some(render_markdown(&doc)?)))
+}
+
+/// Report `err` against every link in a source's batch.
+fn batch_errors(edges: &[PendingLink<'_>], err: &anyhow::Error) -> Vec<LinkBatchError> {
+    let message = format!("{err:#}");
+    edges
+        .iter()
+        .map(|(index, _, _)| LinkBatchError {
+            index: *index,
+            message: message.clone(),
+            missing_target: false,
+        })
+        .collect()
+}
+
+/// Replace an entity file by writing a sibling temp file, verifying it parses, and
+/// renaming it over the target, so an interrupted write never leaves a torn file.
+fn write_entity_atomic(path: &Path, contents: &str) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("Invalid entity path: {}", path.display()))?;
+    let tmp_path = path.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+
+    let result = write_and_verify(&tmp_path, contents).and_then(|()| {
+        std::fs::rename(&tmp_path, path).with_context(|| {
+            format!(
+                "Failed to rename {} to {}",
+                tmp_path.display(),
+                path.display()
+            )
+        })
+    });
+    if result.is_err() && tmp_path.is_file() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+fn write_and_verify(tmp_path: &Path, contents: &str) -> Result<()> {
+    use std::io::Write as _;
+
+    let mut file = std::fs::File::create(tmp_path)
+        .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+    file.write_all(contents.as_bytes())
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+
+    let written = std::fs::read_to_string(tmp_path)
+        .with_context(|| format!("Failed to read {}", tmp_path.display()))?;
+    if written != contents {
+        anyhow::bail!("Short write to {}", tmp_path.display());
+    }
+    parse_markdown(tmp_path, &written)
+        .with_context(|| format!("Refusing to commit unparsable {}", tmp_path.display()))?;
+    Ok(())
+}
+
 fn ensure_project_gitignore(repo_root: &Path) -> Result<()> {
     let path = repo_root.join(".gitignore");
     let existing = std::fs::read_to_string(&path).unwrap_or_default();
@@ -1081,45 +2269,6 @@ fn ensure_project_gitignore(repo_root: &Path) -> Result<()> {
     Ok(())
 }
 
-fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<String>)> {
-    let mut rels = Vec::new();
-
-    for (key, value) in frontmatter {
-        let serde_yaml::Value::String(key) = key else {
-            continue;
-        };
-
-        if METADATA_KEYS.contains(&key.as_str()) {
-            continue;
-        }
-
-        let targets = match value {
-            serde_yaml::Value::Sequence(seq) => seq
-                .iter()
-                .filter_map(|v| match v {
-                    serde_yaml::Value::String(s) => Some(s.clone()),
-                    _ => None,
-                })
-                .collect::<Vec<_>>(),
-            serde_yaml::Value::String(s) => vec![s.clone()],
-            _ => Vec::new(),
-        };
-
-        let targets = targets
-            .into_iter()
-            .filter(|t| looks_like_entity_id(t))
-            .collect::<Vec<_>>();
-
-        if targets.is_empty() {
-            continue;
-        }
-
-        rels.push((key.clone(), targets));
-    }
-
-    rels
-}
-
 fn normalize_tag(tag: &str) -> Option<String> {
     let trimmed = tag.trim();
     if trimmed.is_empty() {
@@ -1,6 +1,6 @@
 use ix_core::entity::EntityKind;
 use ix_core::markdown::{parse_markdown, render_markdown, set_string};
-use ix_core::repo::{IxchelRepo, ListSort};
+use ix_core::repo::{IxchelRepo, LinkError, LinkRequest, ListSort};
 use serde_yaml::Value as YamlValue;
 use tempfile::TempDir;
 
@@ -63,6 +63,37 @@ fn init_writes_ixchel_config_and_gitignore_entries() {
     assert!(gitignore.contains(".ixchel/models/"));
 }
 
+#[test]
+fn init_with_examples_scaffolds_a_valid_repo() {
+    let temp = TempDir::new().expect("create tempdir");
+    std::fs::create_dir_all(temp.path().join(".git")).expect("create .git marker");
+
+    let repo = IxchelRepo::init_from_with_options(temp.path(), false, true).expect("init");
+    assert!(repo.config.schema.contains_key("decision"));
+    assert!(!repo.is_relationship_key("owner"));
+    assert_eq!(
+        repo.config.storage.backend,
+        ix_config::IxchelConfig::default().storage.backend
+    );
+
+    let decisions = repo
+        .list(Some(EntityKind::Decision), ListSort::default())
+        .expect("list decisions");
+    assert_eq!(decisions.len(), 1);
+    let report = repo.check().expect("check");
+    assert!(report.errors.is_empty(), "{:#?}", report.errors);
+
+    let err = IxchelRepo::init_from_with_options(temp.path(), false, true)
+        .expect_err("init without --force");
+    assert!(format!("{err:#}").contains("--force"));
+
+    let repo = IxchelRepo::init_from_with_options(temp.path(), true, true).expect("re-init");
+    let decisions = repo
+        .list(Some(EntityKind::Decision), ListSort::default())
+        .expect("list decisions");
+    assert_eq!(decisions.len(), 1);
+}
+
 #[test]
 fn link_unlink_and_check_roundtrip() {
     let (_temp, repo) = init_temp_git_repo();
@@ -102,6 +133,289 @@ fn link_unlink_and_check_roundtrip() {
     );
 }
 
+#[test]
+fn link_requires_both_endpoints_unless_dangling_allowed() {
+    let (_temp, repo) = init_temp_git_repo();
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+
+    let err = repo
+        .link(&issue.id, "implements", "dec-deadbe")
+        .expect_err("missing target should fail");
+    let msg = format!("{err:#}");
+    assert!(msg.contains("Target does not exist: dec-deadbe"), "{msg}");
+    assert!(!msg.contains("--allow-dangling"), "{msg}");
+    assert!(matches!(
+        err.downcast_ref::<LinkError>(),
+        Some(LinkError::MissingTarget { id, .. }) if id == "dec-deadbe"
+    ));
+
+    let err = repo
+        .link_with_options("iss-deadbe", "implements", &issue.id, true)
+        .expect_err("missing source should fail even when dangling is allowed");
+    let msg = format!("{err:#}");
+    assert!(msg.contains("Source does not exist: iss-deadbe"), "{msg}");
+
+    repo.link_with_options(&issue.id, "implements", "dec-deadbe", true)
+        .expect("dangling link allowed");
+    assert!(
+        repo.unlink(&issue.id, "implements", "dec-deadbe")
+            .expect("unlink dangling target")
+    );
+}
+
+#[test]
+fn failed_link_write_leaves_original_file_intact() {
+    let (_temp, repo) = init_temp_git_repo();
+
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("accepted"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+    let original = std::fs::read_to_string(&issue.path).expect("read issue");
+
+    // A directory squatting on the temp path makes the staged write fail.
+    let file_name = issue.path.file_name().expect("file name").to_string_lossy();
+    let tmp_path = issue.path.with_file_name(format!(".{file_name}.tmp"));
+    std::fs::create_dir(&tmp_path).expect("create blocking dir");
+
+    repo.link(&issue.id, "implements", &decision.id)
+        .expect_err("link should fail when the temp file cannot be written");
+    let after = std::fs::read_to_string(&issue.path).expect("read issue");
+    assert_eq!(after, original);
+
+    std::fs::remove_dir(&tmp_path).expect("remove blocking dir");
+    repo.link(&issue.id, "implements", &decision.id)
+        .expect("link after clearing temp path");
+    assert!(!tmp_path.exists(), "temp file left behind");
+    let raw = std::fs::read_to_string(&issue.path).expect("read issue");
+    let doc = parse_markdown(&issue.path, &raw).expect("parse markdown");
+    assert!(doc.frontmatter.contains_key("implements"));
+}
+
+#[test]
+fn qualified_relationship_targets_keep_properties() {
+    let (_temp, repo) = init_temp_git_repo();
+
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("accepted"))
+        .expect("create decision");
+    let other = repo
+        .create_entity(EntityKind::Decision, "Decision B", Some("accepted"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+
+    let raw = std::fs::read_to_string(&issue.path).expect("read issue");
+    let mut doc = parse_markdown(&issue.path, &raw).expect("parse markdown");
+    let qualified: YamlValue = serde_yaml::from_str(&format!(
+        "[{{ id: {}, since: \"2026-01-01\", note: until migration done }}, {}]",
+        decision.id, other.id
+    ))
+    .expect("parse yaml");
+    doc.frontmatter
+        .insert(YamlValue::String("depends_on".to_string()), qualified);
+    std::fs::write(&issue.path, render_markdown(&doc).expect("render")).expect("write issue");
+
+    let rels = repo.extract_relationship_targets(&doc.frontmatter);
+    assert_eq!(rels.len(), 1);
+    let (rel, targets) = &rels[0];
+    assert_eq!(rel, "depends_on");
+    assert_eq!(targets[0].id, decision.id);
+    assert_eq!(targets[0].properties["since"], "2026-01-01");
+    assert_eq!(targets[0].properties["note"], "until migration done");
+    assert_eq!(targets[1].id, other.id);
+    assert!(targets[1].properties.is_empty());
+    assert_eq!(
+        repo.extract_relationships(&doc.frontmatter),
+        vec![(
+            "depends_on".to_string(),
+            vec![decision.id.clone(), other.id.clone()]
+        )]
+    );
+    assert!(repo.check().expect("check").errors.is_empty());
+
+    let third = repo
+        .create_entity(EntityKind::Decision, "Decision C", Some("accepted"))
+        .expect("create decision");
+    repo.link(&issue.id, "depends_on", &decision.id)
+        .expect("relink qualified target");
+    repo.link(&issue.id, "depends_on", &third.id)
+        .expect("link bare target");
+    assert!(
+        repo.unlink(&issue.id, "depends_on", &other.id)
+            .expect("unlink")
+    );
+
+    let raw = std::fs::read_to_string(&issue.path).expect("read issue");
+    let doc = parse_markdown(&issue.path, &raw).expect("parse markdown");
+    let targets = repo
+        .extract_relationship_targets(&doc.frontmatter)
+        .remove(0)
+        .1;
+    assert_eq!(
+        targets.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(),
+        vec![decision.id.as_str(), third.id.as_str()]
+    );
+    assert_eq!(targets[0].properties["since"], "2026-01-01");
+}
+
+#[test]
+fn strict_link_many_writes_nothing_when_a_source_fails_to_render() {
+    let (_temp, repo) = init_temp_git_repo();
+
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("accepted"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+    std::fs::write(&issue.path, "---\ntitle: [unclosed\n---\n").expect("corrupt issue");
+
+    let links = vec![
+        LinkRequest {
+            from: decision.id.clone(),
+            rel: "related".to_string(),
+            to: issue.id.clone(),
+        },
+        LinkRequest {
+            from: issue.id,
+            rel: "implements".to_string(),
+            to: decision.id.clone(),
+        },
+    ];
+    let before = std::fs::read_to_string(&decision.path).expect("read decision");
+    let err = repo
+        .link_many(&links, false, true)
+        .expect_err("strict batch rejects unreadable source");
+    assert!(format!("{err:#}").contains("link #2"), "{err:#}");
+    let after = std::fs::read_to_string(&decision.path).expect("read decision");
+    assert_eq!(before, after);
+}
+
+#[test]
+fn link_many_batches_writes_and_collects_errors() {
+    let (_temp, repo) = init_temp_git_repo();
+
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("accepted"))
+        .expect("create decision");
+    let other = repo
+        .create_entity(EntityKind::Decision, "Decision B", Some("accepted"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+    repo.link(&issue.id, "implements", &decision.id)
+        .expect("link issue -> decision");
+
+    let request = |rel: &str, to: &str| LinkRequest {
+        from: issue.id.clone(),
+        rel: rel.to_string(),
+        to: to.to_string(),
+    };
+    let links = vec![
+        request("implements", &decision.id),
+        request("implements", &other.id),
+        request("blocks", "iss-deadbe"),
+        request("related", &other.id),
+        request("related", &other.id),
+    ];
+
+    let err = repo
+        .link_many(&links, false, true)
+        .expect_err("strict batch rejects missing target");
+    assert!(format!("{err:#}").contains("link #3"), "{err:#}");
+    let raw = std::fs::read_to_string(&issue.path).expect("read issue");
+    let doc = parse_markdown(&issue.path, &raw).expect("parse markdown");
+    assert!(!doc.frontmatter.contains_key("related"));
+
+    let report = repo.link_many(&links, false, false).expect("link batch");
+    assert_eq!(report.added, 2);
+    assert_eq!(report.skipped, 2);
+    assert_eq!(report.errors.len(), 1);
+    assert_eq!(report.errors[0].index, 2);
+
+    let raw = std::fs::read_to_string(&issue.path).expect("read issue");
+    let doc = parse_markdown(&issue.path, &raw).expect("parse markdown");
+    let implements = doc
+        .frontmatter
+        .get("implements")
+        .and_then(YamlValue::as_sequence)
+        .expect("implements list");
+    assert_eq!(implements.len(), 2);
+    let related = doc
+        .frontmatter
+        .get("related")
+        .and_then(YamlValue::as_sequence)
+        .expect("related list");
+    assert_eq!(related.len(), 1);
+}
+
+#[test]
+fn move_entity_changes_kind_and_relinks_referrers() {
+    let (temp, _repo) = init_temp_git_repo();
+    std::fs::write(
+        temp.path().join(".ixchel/config.toml"),
+        "[schema.decision]\nrequired = [\"date\"]\n",
+    )
+    .expect("write config");
+    let repo = IxchelRepo::open_from(temp.path()).expect("reopen repo");
+
+    let idea = repo
+        .create_entity(EntityKind::Idea, "Cache embeddings", None)
+        .expect("create idea");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Slow sync", Some("open"))
+        .expect("create issue");
+    repo.link(&issue.id, "implements", &idea.id)
+        .expect("link issue -> idea");
+
+    let err = repo
+        .move_entity(&idea.id, EntityKind::Decision, false)
+        .expect_err("missing required date");
+    assert!(format!("{err:#}").contains("missing required field(s): date"));
+
+    let raw = std::fs::read_to_string(&idea.path).expect("read idea");
+    let mut doc = parse_markdown(&idea.path, &raw).expect("parse markdown");
+    set_string(&mut doc.frontmatter, "date", "2025-01-02");
+    std::fs::write(&idea.path, render_markdown(&doc).expect("render")).expect("write idea");
+
+    let plan = repo
+        .move_entity(&idea.id, EntityKind::Decision, true)
+        .expect("dry run");
+    assert!(idea.path.exists());
+    assert!(!plan.new_path.exists());
+    assert_eq!(plan.rewritten, vec![issue.id.clone()]);
+
+    let report = repo
+        .move_entity(&idea.id, EntityKind::Decision, false)
+        .expect("move idea");
+    assert!(report.new_id.starts_with("dec-"));
+    assert_eq!(report.new_id[4..], idea.id[5..]);
+    assert!(!idea.path.exists());
+
+    let moved = repo.read_document(&report.new_id).expect("read moved");
+    assert_eq!(
+        moved.frontmatter.get("type").and_then(YamlValue::as_str),
+        Some("decision")
+    );
+    assert!(moved.body.contains("## "), "body preserved");
+
+    let issue_doc = repo.read_document(&issue.id).expect("read issue");
+    let implements = issue_doc
+        .frontmatter
+        .get("implements")
+        .and_then(YamlValue::as_sequence)
+        .expect("implements list");
+    assert_eq!(implements[0].as_str(), Some(report.new_id.as_str()));
+    assert!(repo.check().expect("check").errors.is_empty());
+}
+
 #[test]
 fn check_reports_broken_and_unknown_links() {
     let (_temp, repo) = init_temp_git_repo();
@@ -222,6 +536,31 @@ fn add_remove_tags_are_idempotent() {
     let raw = std::fs::read_to_string(&path).expect("read issue");
     let doc = parse_markdown(&path, &raw).expect("parse markdown");
     assert!(ix_core::markdown::get_string_list(&doc.frontmatter, "tags").is_empty());
+
+    // Tags match case-insensitively but keep their authored spelling.
+    repo.add_tags(&issue.id, &["Backend".to_string()])
+        .expect("add tags");
+    let changed = repo
+        .add_tags(&issue.id, &["backend".to_string()])
+        .expect("add tags other case");
+    assert!(!changed, "expected case-insensitive add to no-op");
+    let raw = std::fs::read_to_string(&path).expect("read issue");
+    let doc = parse_markdown(&path, &raw).expect("parse markdown");
+    assert_eq!(
+        ix_core::markdown::get_string_list(&doc.frontmatter, "tags"),
+        vec!["Backend"]
+    );
+
+    let changed = repo
+        .remove_tags(&issue.id, &[" BACKEND ".to_string()])
+        .expect("remove tags other case");
+    assert!(
+        changed,
+        "expected case-insensitive remove to change document"
+    );
+    let raw = std::fs::read_to_string(&path).expect("read issue");
+    let doc = parse_markdown(&path, &raw).expect("parse markdown");
+    assert!(ix_core::markdown::get_string_list(&doc.frontmatter, "tags").is_empty());
 }
 
 #[test]
@@ -283,3 +622,357 @@ fn list_sorts_by_timestamps_and_puts_missing_last() {
         ]
     );
 }
+
+#[test]
+fn archive_moves_entity_out_of_active_listing() {
+    let (_temp, repo) = init_temp_git_repo();
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("accepted"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+    repo.link(&issue.id, "implements", &decision.id)
+        .expect("link issue implements decision");
+
+    let archived_path = repo.archive_entity(&decision.id).expect("archive");
+    assert!(!decision.path.exists(), "active file should be moved");
+    assert_eq!(
+        Some(archived_path.clone()),
+        repo.paths.archived_entity_path(&decision.id)
+    );
+
+    let raw = std::fs::read_to_string(&archived_path).expect("read archived");
+    let doc = parse_markdown(&archived_path, &raw).expect("parse markdown");
+    assert_eq!(
+        ix_core::markdown::get_string(&doc.frontmatter, "status").as_deref(),
+        Some("archived")
+    );
+    assert!(ix_core::markdown::get_string(&doc.frontmatter, "archived_at").is_some());
+
+    let active = repo.list(None, ListSort::default()).expect("list");
+    assert!(active.iter().all(|item| item.id != decision.id));
+    let all = repo
+        .list_with_options(None, ListSort::default(), true)
+        .expect("list including archived");
+    assert!(all.iter().any(|item| item.id == decision.id));
+
+    let report = repo.check().expect("check");
+    assert!(
+        report.errors.is_empty(),
+        "links to archived entities are not dangling: {:#?}",
+        report.errors
+    );
+
+    let err = repo
+        .archive_entity(&decision.id)
+        .expect_err("second archive should fail");
+    assert!(format!("{err:#}").contains("already archived"));
+}
+
+#[test]
+fn aliases_resolve_to_canonical_ids() {
+    let (temp, repo) = init_temp_git_repo();
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("accepted"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+
+    let set_aliases = |id: &str, aliases: &[&str]| {
+        let path = repo.paths.entity_path(id).expect("entity path");
+        let raw = std::fs::read_to_string(&path).expect("read entity");
+        let mut doc = parse_markdown(&path, &raw).expect("parse markdown");
+        ix_core::markdown::set_string_list(
+            &mut doc.frontmatter,
+            "aliases",
+            aliases.iter().map(ToString::to_string).collect(),
+        );
+        let rendered = render_markdown(&doc).expect("render markdown");
+        std::fs::write(&path, rendered).expect("write entity");
+    };
+
+    set_aliases(&decision.id, &["ADR-0007"]);
+    assert_eq!(repo.resolve_id("adr-0007").expect("resolve"), decision.id);
+    assert!(
+        repo.read_raw("ADR-0007")
+            .expect("read by alias")
+            .contains("Decision A")
+    );
+
+    repo.link(&issue.id, "implements", "ADR-0007")
+        .expect("link via alias");
+    let report = repo.check().expect("check");
+    assert!(report.errors.is_empty(), "{:#?}", report.errors);
+
+    // The same handle notices edits made outside it.
+    set_aliases(&issue.id, &["ADR-0007"]);
+    let err = repo.resolve_id("ADR-0007").expect_err("ambiguous alias");
+    let msg = format!("{err:#}");
+    assert!(msg.contains("Ambiguous alias ADR-0007"), "{msg}");
+    assert!(
+        msg.contains(&decision.id) && msg.contains(&issue.id),
+        "{msg}"
+    );
+
+    let err = repo.resolve_id("ADR-9999").expect_err("unknown alias");
+    assert!(format!("{err:#}").contains("Unknown entity id or alias"));
+
+    // ...and entities created or deleted through another handle.
+    let other = IxchelRepo::open_from(temp.path()).expect("open second handle");
+    other.delete_entity(&issue.id).expect("delete issue");
+    assert_eq!(repo.resolve_id("ADR-0007").expect("resolve"), decision.id);
+}
+
+#[test]
+fn relationships_config_controls_which_keys_are_links() {
+    let (temp, repo) = init_temp_git_repo();
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", Some("open"))
+        .expect("create issue");
+
+    let path = repo.paths.entity_path(&issue.id).expect("issue path");
+    let raw = std::fs::read_to_string(&path).expect("read issue");
+    let mut doc = parse_markdown(&path, &raw).expect("parse markdown");
+    set_string(&mut doc.frontmatter, "owner", "agt-deadbe");
+    set_string(&mut doc.frontmatter, "depends_on", "dec-deadbe");
+    let rendered = render_markdown(&doc).expect("render markdown");
+    std::fs::write(&path, rendered).expect("write issue");
+
+    let report = repo.check().expect("check");
+    assert_eq!(report.errors.len(), 2, "{:#?}", report.errors);
+
+    let config_path = temp.path().join(".ixchel/config.toml");
+    let write_config = |extra: &str| {
+        let mut raw = std::fs::read_to_string(&config_path).expect("read config");
+        raw.push_str(extra);
+        std::fs::write(&config_path, raw).expect("write config");
+        IxchelRepo::open_from(temp.path()).expect("reopen repo")
+    };
+
+    let repo = write_config("\n[relationships]\nmetadata_keys = [\"owner\"]\n");
+    let rels = repo.extract_relationships(&doc.frontmatter);
+    assert_eq!(
+        rels,
+        vec![("depends_on".to_string(), vec!["dec-deadbe".to_string()])]
+    );
+    let report = repo.check().expect("check");
+    assert_eq!(report.errors.len(), 1, "{:#?}", report.errors);
+    assert!(report.errors[0].message.contains("depends_on"));
+
+    let repo = write_config("allowed = [\"blocks\"]\n");
+    assert!(repo.extract_relationships(&doc.frontmatter).is_empty());
+    let report = repo.check().expect("check");
+    assert!(report.errors.is_empty(), "{:#?}", report.errors);
+}
+
+#[test]
+fn open_with_config_path_replaces_project_config() {
+    let (temp, _repo) = init_temp_git_repo();
+    std::fs::write(
+        temp.path().join(".ixchel/config.toml"),
+        "[storage]\nbackend = \"surrealdb\"\n",
+    )
+    .expect("write project config");
+
+    let override_path = temp.path().join("ci.toml");
+    std::fs::write(
+        &override_path,
+        "[storage]\nbackend = \"memory\"\n\n[relationships]\nmetadata_keys = [\"owner\"]\n",
+    )
+    .expect("write override config");
+
+    let repo = IxchelRepo::open_from_with_config(temp.path(), Some(&override_path))
+        .expect("open with config");
+    assert_eq!(repo.config.storage.backend, "memory");
+    assert!(!repo.is_relationship_key("owner"));
+
+    let err = IxchelRepo::open_from_with_config(temp.path(), Some(&temp.path().join("nope.toml")))
+        .expect_err("missing config file");
+    assert!(format!("{err:#}").contains("nope.toml"));
+}
+
+#[test]
+fn read_documents_preserves_order_and_names_failures() {
+    let (_temp, repo) = init_temp_git_repo();
+    let mut ids = (0..20)
+        .map(|i| {
+            repo.create_entity(EntityKind::Issue, &format!("Issue {i}"), None)
+                .expect("create issue")
+                .id
+        })
+        .collect::<Vec<_>>();
+    ids.insert(7, "dec-deadbe".to_string());
+
+    let docs = repo.read_documents(&ids);
+    assert_eq!(docs.len(), ids.len());
+    for (index, (id, doc)) in ids.iter().zip(&docs).enumerate() {
+        if index == 7 {
+            let err = doc.as_ref().expect_err("missing entity");
+            assert!(format!("{err:#}").contains("dec-deadbe"), "{err:#}");
+            continue;
+        }
+        let doc = doc.as_ref().expect("read document");
+        assert_eq!(
+            ix_core::markdown::get_string(&doc.frontmatter, "id").as_deref(),
+            Some(id.as_str())
+        );
+    }
+}
+
+#[test]
+fn check_validates_dates_and_configured_statuses() {
+    let (temp, repo) = init_temp_git_repo();
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", Some("approved"))
+        .expect("create decision");
+
+    let path = repo.paths.entity_path(&decision.id).expect("decision path");
+    let raw = std::fs::read_to_string(&path).expect("read decision");
+    let mut doc = parse_markdown(&path, &raw).expect("parse markdown");
+    set_string(&mut doc.frontmatter, "date", "2026-13-40");
+    set_string(&mut doc.frontmatter, "updated_at", "yesterday");
+    let rendered = render_markdown(&doc).expect("render markdown");
+    std::fs::write(&path, rendered).expect("write decision");
+
+    let messages = |repo: &IxchelRepo| {
+        repo.check()
+            .expect("check")
+            .errors
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<_>>()
+    };
+
+    let found = messages(&repo);
+    assert_eq!(
+        found,
+        vec![
+            "invalid date 'yesterday' in frontmatter key 'updated_at'".to_string(),
+            "invalid date '2026-13-40' in frontmatter key 'date'".to_string(),
+        ]
+    );
+
+    let config_path = temp.path().join(".ixchel/config.toml");
+    let mut config = std::fs::read_to_string(&config_path).expect("read config");
+    config.push_str(
+        "\n[schema.decision]\nstatuses = [\"proposed\", \"accepted\"]\ndate_format = \"%d/%m/%Y\"\n",
+    );
+    std::fs::write(&config_path, config).expect("write config");
+    let repo = IxchelRepo::open_from(temp.path()).expect("reopen repo");
+
+    let found = messages(&repo);
+    assert!(
+        found.contains(&"invalid status 'approved' in frontmatter key 'status'".to_string()),
+        "{found:#?}"
+    );
+
+    set_string(&mut doc.frontmatter, "date", "06/01/2026");
+    set_string(&mut doc.frontmatter, "updated_at", "2026-01-06T00:00:00Z");
+    set_string(&mut doc.frontmatter, "status", "accepted");
+    let rendered = render_markdown(&doc).expect("render markdown");
+    std::fs::write(&path, rendered).expect("write decision");
+    assert!(messages(&repo).is_empty());
+
+    repo.archive_entity(&decision.id).expect("archive decision");
+    assert!(messages(&repo).is_empty());
+}
+
+#[test]
+fn validate_schema_reports_per_field_violations_and_skips_unschematized_kinds() {
+    let (temp, _repo) = init_temp_git_repo();
+    std::fs::write(
+        temp.path().join(".ixchel/config.toml"),
+        "[schema.decision]\nstatuses = [\"proposed\", \"accepted\"]\nrequired = [\"date\", \"deciders\"]\n",
+    )
+    .expect("write config");
+    let repo = IxchelRepo::open_from(temp.path()).expect("reopen repo");
+
+    let good = repo
+        .create_entity(EntityKind::Decision, "Good", Some("accepted"))
+        .expect("create decision");
+    let bad = repo
+        .create_entity(EntityKind::Decision, "Bad", Some("done"))
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue", Some("open"))
+        .expect("create issue");
+
+    for (summary, date) in [(&good, "2025-01-02"), (&bad, "01/02/2025")] {
+        let raw = std::fs::read_to_string(&summary.path).expect("read entity");
+        let mut doc = parse_markdown(&summary.path, &raw).expect("parse markdown");
+        set_string(&mut doc.frontmatter, "date", date);
+        if summary.id == good.id {
+            set_string(&mut doc.frontmatter, "deciders", "core team");
+        }
+        let rendered = render_markdown(&doc).expect("render markdown");
+        std::fs::write(&summary.path, rendered).expect("write entity");
+    }
+
+    let checks = repo.validate_schema().expect("validate schema");
+    let find = |id: &str| {
+        checks
+            .iter()
+            .find(|c| c.id == id)
+            .unwrap_or_else(|| panic!("missing {id}"))
+    };
+
+    assert!(find(&good.id).has_schema);
+    assert!(find(&good.id).violations.is_empty(), "{checks:?}");
+
+    let mut fields = find(&bad.id)
+        .violations
+        .iter()
+        .map(|v| v.field.as_str())
+        .collect::<Vec<_>>();
+    fields.sort_unstable();
+    assert_eq!(fields, ["date", "deciders", "status"], "{checks:?}");
+
+    assert!(!find(&issue.id).has_schema);
+    assert!(find(&issue.id).violations.is_empty());
+}
+
+#[test]
+fn tags_are_counted_case_insensitively_and_filter_listing() {
+    let (_temp, repo) = init_temp_git_repo();
+    let decision = repo
+        .create_entity(EntityKind::Decision, "Decision A", None)
+        .expect("create decision");
+    let issue = repo
+        .create_entity(EntityKind::Issue, "Issue A", None)
+        .expect("create issue");
+    let idea = repo
+        .create_entity(EntityKind::Idea, "Idea A", None)
+        .expect("create idea");
+
+    let tags = |items: &[&str]| items.iter().map(ToString::to_string).collect::<Vec<_>>();
+    repo.add_tags(&decision.id, &tags(&["Storage", "cli"]))
+        .expect("tag decision");
+    repo.add_tags(&issue.id, &tags(&["storage", "STORAGE"]))
+        .expect("tag issue");
+    repo.add_tags(&idea.id, &tags(&["cli"])).expect("tag idea");
+
+    let collected = repo.collect_tags(None).expect("collect tags");
+    assert_eq!(collected.len(), 2, "{collected:?}");
+    let storage = collected
+        .iter()
+        .find(|(tag, _)| tag.eq_ignore_ascii_case("storage"))
+        .map(|(_, ids)| ids.len());
+    assert_eq!(storage, Some(2));
+
+    let listed = repo
+        .list_tagged(None, ListSort::default(), false, &tags(&["STORAGE"]))
+        .expect("list by tag");
+    let mut ids = listed.into_iter().map(|item| item.id).collect::<Vec<_>>();
+    ids.sort();
+    let mut expected = vec![decision.id.clone(), issue.id];
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    let listed = repo
+        .list_tagged(None, ListSort::default(), false, &tags(&["storage", "cli"]))
+        .expect("list by tags");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, decision.id);
+}
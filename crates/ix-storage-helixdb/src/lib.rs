@@ -23,17 +23,6 @@ use serde_yaml::Value as YamlValue;
 use uuid::Uuid;
 
 const NODE_LABEL: &str = "IXCHEL_ENTITY";
-const METADATA_KEYS: &[&str] = &[
-    "id",
-    "type",
-    "title",
-    "status",
-    "date",
-    "created_at",
-    "updated_at",
-    "created_by",
-    "tags",
-];
 
 pub struct HelixDbIndex {
     repo_root: PathBuf,
@@ -214,6 +203,16 @@ impl HelixDbIndex {
 
 impl IndexBackend for HelixDbIndex {
     fn sync(&mut self, repo: &IxchelRepo) -> Result<SyncStats> {
+        self.sync_with_progress(repo, &mut |_| {})
+    }
+
+    /// Rebuild the graph from the entity files, reporting each scanned entity.
+    #[allow(clippy::too_many_lines)]
+    fn sync_with_progress(
+        &mut self,
+        repo: &IxchelRepo,
+        progress: &mut dyn FnMut(&SyncStats),
+    ) -> Result<SyncStats> {
         self.rebuild_storage()?;
 
         let mut stats = SyncStats::default();
@@ -228,6 +227,7 @@ impl IndexBackend for HelixDbIndex {
 
         for entity_path in iter_entity_paths(repo)? {
             stats.scanned += 1;
+            progress(&stats);
 
             let raw = std::fs::read_to_string(&entity_path)
                 .with_context(|| format!("Failed to read {}", entity_path.display()))?;
@@ -302,7 +302,7 @@ impl IndexBackend for HelixDbIndex {
             id_to_node.insert(id.clone(), node_id);
             records.push(EntityRecord {
                 from_node: node_id,
-                rels: extract_relationships(&doc.frontmatter),
+                rels: extract_relationships(repo, &doc.frontmatter),
             });
 
             stats.added += 1;
@@ -387,14 +387,18 @@ impl IndexBackend for HelixDbIndex {
         Ok(hits)
     }
 
+    /// Open a read transaction and query the `id` index, so a missing or corrupt
+    /// index table fails here rather than on the first search.
     fn health_check(&self) -> Result<()> {
-        let _rtxn = self
+        let storage = self
             .storage
             .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("Storage not initialized"))?
+            .ok_or_else(|| anyhow::anyhow!("Storage not initialized"))?;
+        let rtxn = storage
             .graph_env
             .read_txn()
             .map_err(|e| anyhow::anyhow!("Failed to start read transaction: {e}"))?;
+        lookup_node_by_entity_id(storage, &rtxn, "")?;
         Ok(())
     }
 }
@@ -438,30 +442,23 @@ fn normalize_path(repo_root: &Path, file_path: &Path) -> String {
 fn iter_entity_paths(repo: &IxchelRepo) -> Result<Vec<PathBuf>> {
     let mut out = Vec::new();
 
-    for kind in [
-        EntityKind::Decision,
-        EntityKind::Issue,
-        EntityKind::Idea,
-        EntityKind::Report,
-        EntityKind::Source,
-        EntityKind::Citation,
-        EntityKind::Agent,
-        EntityKind::Session,
-    ] {
-        let dir = repo.paths.kind_dir(kind);
-        if !dir.exists() {
-            continue;
-        }
-
-        for entry in
-            std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
-        {
-            let entry = entry?;
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) != Some("md") {
+    for kind in EntityKind::ALL {
+        // Archived entities stay indexed; search filters them out by default.
+        for dir in [repo.paths.kind_dir(kind), repo.paths.archive_kind_dir(kind)] {
+            if !dir.exists() {
                 continue;
             }
-            out.push(path);
+
+            for entry in std::fs::read_dir(&dir)
+                .with_context(|| format!("Failed to read {}", dir.display()))?
+            {
+                let entry = entry?;
+                let path = entry.path();
+                if path.extension().and_then(|s| s.to_str()) != Some("md") {
+                    continue;
+                }
+                out.push(path);
+            }
         }
     }
 
@@ -539,7 +536,10 @@ fn build_embedding_text(title: &str, body: &str, tags: &[String], kind: EntityKi
     )
 }
 
-fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<String>)> {
+fn extract_relationships(
+    repo: &IxchelRepo,
+    frontmatter: &serde_yaml::Mapping,
+) -> Vec<(String, Vec<String>)> {
     let mut rels = Vec::new();
     let mut seen: BTreeSet<String> = BTreeSet::new();
 
@@ -548,7 +548,7 @@ fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<
             continue;
         };
 
-        if METADATA_KEYS.contains(&key.as_str()) {
+        if !repo.is_relationship_key(key) {
             continue;
         }
 
@@ -7,7 +7,7 @@ mod manifest;
 mod schema;
 mod types;
 
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -16,7 +16,7 @@ use manifest::{ManifestEntry, SyncAction, SyncManifest};
 
 use anyhow::{Context, Result};
 use ix_core::entity::{EntityKind, kind_from_id};
-use ix_core::index::{IndexBackend, SearchHit, SyncStats};
+use ix_core::index::{IndexBackend, IndexManifest, SearchHit, SyncStats};
 use ix_core::markdown::{get_string, get_string_list, parse_markdown};
 use ix_core::repo::IxchelRepo;
 use ix_embeddings::Embedder;
@@ -27,18 +27,6 @@ use tokio::runtime::Runtime;
 
 pub use types::{EntityRecord, SearchResult};
 
-const METADATA_KEYS: &[&str] = &[
-    "id",
-    "type",
-    "title",
-    "status",
-    "date",
-    "created_at",
-    "updated_at",
-    "created_by",
-    "tags",
-];
-
 /// SurrealDB-backed index for Ixchel entities.
 ///
 /// Uses `SurrealDB`'s embedded mode with either `RocksDB` or `SurrealKV` for persistence.
@@ -52,6 +40,8 @@ pub struct SurrealDbIndex {
     runtime: Arc<Runtime>,
     embedder: Embedder,
     engine: String,
+    /// Embeddings keyed by content hash, reused by the next sync instead of re-embedding.
+    embedding_cache: HashMap<String, Vec<f32>>,
 }
 
 impl SurrealDbIndex {
@@ -93,6 +83,7 @@ impl SurrealDbIndex {
             runtime,
             embedder,
             engine,
+            embedding_cache: HashMap::new(),
         })
     }
 
@@ -240,6 +231,22 @@ impl SurrealDbIndex {
         Ok(())
     }
 
+    /// Load every stored embedding keyed by the content hash it was computed from.
+    fn load_embeddings(&self) -> Result<HashMap<String, Vec<f32>>> {
+        let runtime = self.runtime.clone();
+        self.with_db(|db| {
+            let records: Vec<types::EmbeddingRecord> = runtime.block_on(async {
+                db.query("SELECT content_hash, embedding FROM entity")
+                    .await?
+                    .take(0)
+            })?;
+            Ok(records
+                .into_iter()
+                .map(|r| (r.content_hash, r.embedding))
+                .collect())
+        })
+    }
+
     fn embed(&self, text: &str) -> Result<Vec<f32>> {
         self.embedder
             .embed(text)
@@ -422,12 +429,20 @@ impl SurrealDbIndex {
 }
 
 impl IndexBackend for SurrealDbIndex {
+    fn sync(&mut self, repo: &IxchelRepo) -> Result<SyncStats> {
+        self.sync_with_progress(repo, &mut |_| {})
+    }
+
     /// Sync entities from the filesystem to the database.
     ///
     /// Uses incremental sync by default: compares content hashes against
     /// a stored manifest and only updates changed entities.
     #[allow(clippy::significant_drop_tightening, clippy::too_many_lines)]
-    fn sync(&mut self, repo: &IxchelRepo) -> Result<SyncStats> {
+    fn sync_with_progress(
+        &mut self,
+        repo: &IxchelRepo,
+        progress: &mut dyn FnMut(&SyncStats),
+    ) -> Result<SyncStats> {
         // Check if database exists - if not, do a full rebuild
         let db_exists = self.db_path.exists();
 
@@ -473,6 +488,7 @@ impl IndexBackend for SurrealDbIndex {
 
         for entity_path in iter_entity_paths(repo)? {
             stats.scanned += 1;
+            progress(&stats);
 
             let raw = std::fs::read_to_string(&entity_path)
                 .with_context(|| format!("Failed to read {}", entity_path.display()))?;
@@ -520,7 +536,14 @@ impl IndexBackend for SurrealDbIndex {
             let entity_status = get_string(&doc.frontmatter, "status").unwrap_or_default();
 
             let embedding_text = build_embedding_text(&title, &doc.body, &tags, kind);
-            let embedding = self.embed(&embedding_text)?;
+            let embedding = match self
+                .embedding_cache
+                .remove(&content_hash)
+                .filter(|cached| cached.len() == dimension)
+            {
+                Some(cached) => cached,
+                None => self.embed(&embedding_text)?,
+            };
 
             let record = EntityRecord {
                 record_id: None,
@@ -569,7 +592,7 @@ impl IndexBackend for SurrealDbIndex {
             id_to_record_id.insert(id.clone(), id.clone());
             pending_relations.push(PendingRelation {
                 from_record_id: id,
-                rels: extract_relationships(&doc.frontmatter),
+                rels: extract_relationships(repo, &doc.frontmatter),
             });
         }
 
@@ -593,6 +616,23 @@ impl IndexBackend for SurrealDbIndex {
         Ok(stats)
     }
 
+    /// Drop the database and re-sync, reusing stored embeddings by content hash unless
+    /// `reembed` is set or the index was embedded with a different provider or model.
+    fn rebuild(&mut self, repo: &IxchelRepo, reembed: bool) -> Result<SyncStats> {
+        let reusable = IndexManifest::load(&repo.paths)?
+            .is_some_and(|manifest| manifest.embeddings_match(&repo.config));
+        self.embedding_cache = if reembed || !reusable || !self.db_path.exists() {
+            HashMap::new()
+        } else {
+            self.load_embeddings()?
+        };
+
+        self.rebuild_database()?;
+        let stats = self.sync(repo);
+        self.embedding_cache.clear();
+        stats
+    }
+
     fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchHit>> {
         let embedding = self.embed(query)?;
         let runtime = self.runtime.clone();
@@ -684,30 +724,23 @@ fn normalize_path(repo_root: &Path, file_path: &Path) -> String {
 fn iter_entity_paths(repo: &IxchelRepo) -> Result<Vec<PathBuf>> {
     let mut out = Vec::new();
 
-    for kind in [
-        EntityKind::Decision,
-        EntityKind::Issue,
-        EntityKind::Idea,
-        EntityKind::Report,
-        EntityKind::Source,
-        EntityKind::Citation,
-        EntityKind::Agent,
-        EntityKind::Session,
-    ] {
-        let dir = repo.paths.kind_dir(kind);
-        if !dir.exists() {
-            continue;
-        }
-
-        for entry in
-            std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
-        {
-            let entry = entry?;
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) != Some("md") {
+    for kind in EntityKind::ALL {
+        // Archived entities stay indexed; search filters them out by default.
+        for dir in [repo.paths.kind_dir(kind), repo.paths.archive_kind_dir(kind)] {
+            if !dir.exists() {
                 continue;
             }
-            out.push(path);
+
+            for entry in std::fs::read_dir(&dir)
+                .with_context(|| format!("Failed to read {}", dir.display()))?
+            {
+                let entry = entry?;
+                let path = entry.path();
+                if path.extension().and_then(|s| s.to_str()) != Some("md") {
+                    continue;
+                }
+                out.push(path);
+            }
         }
     }
 
@@ -728,7 +761,10 @@ fn build_embedding_text(title: &str, body: &str, tags: &[String], kind: EntityKi
     )
 }
 
-fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<String>)> {
+fn extract_relationships(
+    repo: &IxchelRepo,
+    frontmatter: &serde_yaml::Mapping,
+) -> Vec<(String, Vec<String>)> {
     let mut rels = Vec::new();
     let mut seen: BTreeSet<String> = BTreeSet::new();
 
@@ -737,7 +773,7 @@ fn extract_relationships(frontmatter: &serde_yaml::Mapping) -> Vec<(String, Vec<
             continue;
         };
 
-        if METADATA_KEYS.contains(&key.as_str()) {
+        if !repo.is_relationship_key(key) {
             continue;
         }
 
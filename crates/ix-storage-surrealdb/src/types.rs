@@ -54,6 +54,13 @@ pub struct SearchResult {
     pub distance: f64,
 }
 
+/// Stored embedding and the content hash it was computed from.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmbeddingRecord {
+    pub content_hash: String,
+    pub embedding: Vec<f32>,
+}
+
 /// Result of an outgoing/incoming neighbor query.
 #[derive(Debug, Clone, Deserialize)]
 pub struct NeighborResult {
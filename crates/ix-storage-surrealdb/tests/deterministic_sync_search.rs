@@ -1,5 +1,8 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use ix_core::entity::EntityKind;
-use ix_core::index::IndexBackend;
+use ix_core::index::{INDEX_SCHEMA_VERSION, IndexBackend, IndexManifest};
 use ix_core::markdown::{parse_markdown, render_markdown};
 use ix_core::repo::IxchelRepo;
 use ix_embeddings::{Embedder, EmbeddingProvider};
@@ -8,17 +11,25 @@ use tempfile::TempDir;
 struct HashEmbeddingProvider {
     dimension: usize,
     model_name: String,
+    calls: Arc<AtomicUsize>,
 }
 
 impl HashEmbeddingProvider {
     fn new(dimension: usize) -> Self {
+        Self::counting(dimension, Arc::default())
+    }
+
+    /// Like [`Self::new`], incrementing `calls` for every text embedded.
+    fn counting(dimension: usize, calls: Arc<AtomicUsize>) -> Self {
         Self {
             dimension,
             model_name: "hash-v1".to_string(),
+            calls,
         }
     }
 
     fn embed_impl(&self, text: &str) -> Vec<f32> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
         let mut vector = vec![0.0f32; self.dimension];
 
         for token in text
@@ -121,3 +132,42 @@ fn deterministic_sync_indexes_graph_and_vectors() {
         .expect("outgoing implements");
     assert_eq!(outgoing, vec![decision.id]);
 }
+
+#[test]
+fn rebuild_reembeds_when_the_embedding_model_changes() {
+    let temp = TempDir::new().expect("tempdir");
+    let mut repo = IxchelRepo::init_at(temp.path(), false).expect("init ixchel repo");
+
+    let alpha = repo
+        .create_entity(EntityKind::Source, "Alpha Source", None)
+        .expect("create alpha source");
+    replace_entity_body(&repo, &alpha.id, "alpha");
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let provider = HashEmbeddingProvider::counting(32, Arc::clone(&calls));
+    let embedder = Embedder::from_provider(Box::new(provider));
+    let mut index = ix_storage_surrealdb::SurrealDbIndex::open_with_embedder(&repo, embedder)
+        .expect("open index");
+    index.sync(&repo).expect("sync");
+
+    let manifest: IndexManifest = serde_json::from_value(serde_json::json!({
+        "schema_version": INDEX_SCHEMA_VERSION,
+        "backend": "surrealdb",
+        "embedding_provider": repo.config.embedding.provider,
+        "embedding_model": repo.config.embedding.model,
+        "entity_count": 1,
+        "last_sync": "2026-01-01T00:00:00Z",
+    }))
+    .expect("build manifest");
+    manifest.save(&repo.paths).expect("save manifest");
+
+    calls.store(0, Ordering::SeqCst);
+    index.rebuild(&repo, false).expect("rebuild");
+    assert_eq!(calls.load(Ordering::SeqCst), 0, "same model reuses vectors");
+
+    repo.config.embedding.model = "other-model".to_string();
+    index
+        .rebuild(&repo, false)
+        .expect("rebuild after model change");
+    assert_eq!(calls.load(Ordering::SeqCst), 1, "changed model re-embeds");
+}